@@ -0,0 +1,64 @@
+//! criterion benchmarks for every registered solution, grouped by parse, part1 and part2, or
+//! both_parts for days that solve both at once.
+//! days without an input file are skipped.
+
+use std::{fs, path::Path};
+
+use advent_of_code::{AoCDate, Phase, PhaseRunner, get_solutions};
+use criterion::{BenchmarkGroup, Criterion, measurement::WallTime};
+
+struct GroupRunner<'group, 'criterion> {
+    group: &'group mut BenchmarkGroup<'criterion, WallTime>,
+    phase: Phase,
+    id: String,
+}
+
+impl PhaseRunner for GroupRunner<'_, '_> {
+    fn run_phase(&mut self, phase: Phase, routine: &mut dyn FnMut()) {
+        if phase == self.phase {
+            self.group
+                .bench_function(&self.id, |b| b.iter(&mut *routine));
+        }
+    }
+}
+
+fn main() {
+    let mut criterion = Criterion::default().configure_from_args();
+
+    let mut solutions = get_solutions().into_iter().collect::<Vec<_>>();
+    solutions.sort_unstable_by_key(|(date, _)| *date);
+
+    let inputs = solutions
+        .into_iter()
+        .filter_map(|(AoCDate(year, day), solution)| {
+            let path = format!("./input/year{year}/day{day:02}.txt");
+
+            match fs::read_to_string(Path::new(&path)) {
+                Ok(input) => Some((format!("{year}-{day:02}"), solution, input)),
+                Err(_) => {
+                    eprintln!("skipping {year}-{day:02}: no input at {path}");
+                    None
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    for phase in Phase::ALL {
+        let mut group = criterion.benchmark_group(phase.name());
+
+        for (id, solution, input) in &inputs {
+            (solution.phases_fn)(
+                input,
+                &mut GroupRunner {
+                    group: &mut group,
+                    phase,
+                    id: id.clone(),
+                },
+            );
+        }
+
+        group.finish();
+    }
+
+    criterion.final_summary();
+}
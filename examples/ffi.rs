@@ -0,0 +1,147 @@
+//! every registered solution behind a C ABI, for driving them from C or C++:
+//!
+//! ```sh
+//! $ cargo build --release --example ffi
+//! $ c++ harness.cpp -I include -L target/release/examples -lffi
+//! ```
+//!
+//! the matching header is `include/advent_of_code.h`, regenerate it with
+//! `UPDATE_FFI_HEADER=1 cargo test --test ffi` after changing anything below. bump
+//! `AOC_ABI_VERSION` as well whenever a type or a signature changes.
+
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use advent_of_code::{AoCDate, Phase, get_solutions};
+
+/// the version of the types and functions in this header. a harness compiled against one
+/// version must not load a library of another, check `aoc_abi_version` before anything else
+pub const AOC_ABI_VERSION: u32 = 2;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AocDate {
+    pub year: u16,
+    pub day: u8,
+}
+
+/// answer and timings of a single part, parsing is timed separately. days that solve both parts
+/// at once only set `both_parts_nanos`, the other timings are 0 then and the other way around
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AocPartResult {
+    pub answer: u64,
+    pub parse_nanos: u64,
+    pub part_nanos: u64,
+    pub both_parts_nanos: u64,
+}
+
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AocStatus {
+    Ok = 0,
+    UnknownDay = 1,
+    /// part is not 1 or 2
+    InvalidPart = 2,
+    /// input is not valid utf-8
+    InvalidInput = 3,
+    /// a required pointer was null
+    NullPointer = 4,
+    /// the solution panicked, usually because the input is malformed
+    Panicked = 5,
+}
+
+/// the `AOC_ABI_VERSION` this library was built with
+#[unsafe(no_mangle)]
+pub extern "C" fn aoc_abi_version() -> u32 {
+    AOC_ABI_VERSION
+}
+
+/// writes up to `capacity` registered days to `solutions`, sorted by date, and returns how many
+/// days there are in total. call with a null `solutions` to only get the count.
+///
+/// # Safety
+///
+/// `solutions` must be null or valid for writing `capacity` dates.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn aoc_list_solutions(solutions: *mut AocDate, capacity: usize) -> usize {
+    // panics must not unwind into the caller
+    let dates = catch_unwind(|| {
+        let mut dates = get_solutions().into_keys().collect::<Vec<_>>();
+        dates.sort_unstable();
+        dates
+    })
+    .unwrap_or_default();
+
+    if !solutions.is_null() {
+        for (index, AoCDate(year, day)) in dates.iter().copied().take(capacity).enumerate() {
+            unsafe { solutions.add(index).write(AocDate { year, day }) };
+        }
+    }
+
+    dates.len()
+}
+
+/// parses `input` and solves one part of a day. `result` is only written on success.
+///
+/// # Safety
+///
+/// `input` must be valid for reading `input_len` bytes, or null if `input_len` is 0, and
+/// `result` valid for writing.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn aoc_solve(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: *const u8,
+    input_len: usize,
+    result: *mut AocPartResult,
+) -> AocStatus {
+    // an empty input may come as a null pointer
+    if (input.is_null() && input_len > 0) || result.is_null() {
+        return AocStatus::NullPointer;
+    }
+
+    if !(1..=2).contains(&part) {
+        return AocStatus::InvalidPart;
+    }
+
+    let input = if input.is_null() {
+        &[]
+    } else {
+        unsafe { std::slice::from_raw_parts(input, input_len) }
+    };
+    let Ok(input) = std::str::from_utf8(input) else {
+        return AocStatus::InvalidInput;
+    };
+
+    let run = catch_unwind(AssertUnwindSafe(|| {
+        get_solutions()
+            .get(&AoCDate(year, day))
+            .map(|solution| (solution.part_fn)(input, part))
+    }));
+
+    match run {
+        Ok(Some(part_result)) => {
+            unsafe {
+                result.write(part_result.phases.iter().fold(
+                    AocPartResult {
+                        answer: part_result.answer,
+                        ..AocPartResult::default()
+                    },
+                    |mut result, (phase, time)| {
+                        let nanos = time.as_nanos() as u64;
+                        match phase {
+                            Phase::Parse => result.parse_nanos = nanos,
+                            Phase::Part1 | Phase::Part2 => result.part_nanos = nanos,
+                            Phase::BothParts => result.both_parts_nanos = nanos,
+                        }
+                        result
+                    },
+                ))
+            };
+            AocStatus::Ok
+        }
+        Ok(None) => AocStatus::UnknownDay,
+        Err(_) => AocStatus::Panicked,
+    }
+}
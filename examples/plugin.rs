@@ -0,0 +1,16 @@
+//! every registered solution as a hot-reloadable plugin:
+//!
+//! ```sh
+//! $ cargo build --release --example plugin
+//! $ cargo run --release -- day 2024 6 --plugin target/release/examples/libplugin.so
+//! ```
+
+use advent_of_code::plugin::{PLUGIN_ABI_VERSION, PluginDeclaration, declaration};
+
+#[unsafe(no_mangle)]
+pub static AOC_PLUGIN_ABI_VERSION: u32 = PLUGIN_ABI_VERSION;
+
+#[unsafe(no_mangle)]
+pub extern "C" fn aoc_plugin_entry() -> *const PluginDeclaration {
+    declaration()
+}
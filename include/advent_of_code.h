@@ -0,0 +1,86 @@
+#ifndef ADVENT_OF_CODE_H
+#define ADVENT_OF_CODE_H
+
+/* generated by cbindgen from examples/ffi.rs, do not edit */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// the version of the types and functions in this header. a harness compiled against one
+// version must not load a library of another, check `aoc_abi_version` before anything else
+#define AOC_ABI_VERSION 2
+
+enum AocStatus
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint32_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  AOC_STATUS_OK = 0,
+  AOC_STATUS_UNKNOWN_DAY = 1,
+  // part is not 1 or 2
+  AOC_STATUS_INVALID_PART = 2,
+  // input is not valid utf-8
+  AOC_STATUS_INVALID_INPUT = 3,
+  // a required pointer was null
+  AOC_STATUS_NULL_POINTER = 4,
+  // the solution panicked, usually because the input is malformed
+  AOC_STATUS_PANICKED = 5,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum AocStatus AocStatus;
+#else
+typedef uint32_t AocStatus;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+typedef struct AocDate {
+  uint16_t year;
+  uint8_t day;
+} AocDate;
+
+// answer and timings of a single part, parsing is timed separately. days that solve both parts
+// at once only set `both_parts_nanos`, the other timings are 0 then and the other way around
+typedef struct AocPartResult {
+  uint64_t answer;
+  uint64_t parse_nanos;
+  uint64_t part_nanos;
+  uint64_t both_parts_nanos;
+} AocPartResult;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// the `AOC_ABI_VERSION` this library was built with
+uint32_t aoc_abi_version(void);
+
+// writes up to `capacity` registered days to `solutions`, sorted by date, and returns how many
+// days there are in total. call with a null `solutions` to only get the count.
+//
+// # Safety
+//
+// `solutions` must be null or valid for writing `capacity` dates.
+size_t aoc_list_solutions(struct AocDate *solutions, size_t capacity);
+
+// parses `input` and solves one part of a day. `result` is only written on success.
+//
+// # Safety
+//
+// `input` must be valid for reading `input_len` bytes, or null if `input_len` is 0, and
+// `result` valid for writing.
+AocStatus aoc_solve(uint16_t year,
+                    uint8_t day,
+                    uint8_t part,
+                    const uint8_t *input,
+                    size_t input_len,
+                    struct AocPartResult *result);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* ADVENT_OF_CODE_H */
@@ -0,0 +1,239 @@
+//! `--pin-cpu`, `--worker-cpus` and `--isolate` keep the scheduler from moving the benchmark
+//! around. microsecond days are dominated by migrations and frequency changes otherwise.
+
+use std::{collections::BTreeSet, fs, mem};
+
+use anyhow::Context;
+use owo_colors::OwoColorize as _;
+
+#[derive(Clone, Debug)]
+pub struct CpuList(pub Vec<usize>);
+
+/// parses a cpu list like `2`, `2,4` or `2-5,8` as used by `taskset` and sysfs
+pub fn parse_cpu_list(list: &str) -> Result<CpuList, String> {
+    let mut cpus = Vec::new();
+
+    for range in list.split(',') {
+        let (start, end) = range.split_once('-').unwrap_or((range, range));
+        let parse = |cpu: &str| {
+            cpu.trim()
+                .parse::<usize>()
+                .map_err(|_| format!("`{cpu}` is not a cpu number"))
+        };
+        let (start, end) = (parse(start)?, parse(end)?);
+
+        if start > end {
+            return Err(format!("`{range}` is an empty range"));
+        }
+        cpus.extend(start..=end);
+    }
+
+    Ok(CpuList(cpus))
+}
+
+/// the cpus this process may run on
+pub fn allowed_cpus() -> anyhow::Result<BTreeSet<usize>> {
+    let mut set: libc::cpu_set_t = unsafe { mem::zeroed() };
+    if unsafe { libc::sched_getaffinity(0, mem::size_of::<libc::cpu_set_t>(), &mut set) } != 0 {
+        return Err(std::io::Error::last_os_error()).context("sched_getaffinity failed");
+    }
+
+    Ok((0..libc::CPU_SETSIZE as usize)
+        .filter(|&cpu| unsafe { libc::CPU_ISSET(cpu, &set) })
+        .collect())
+}
+
+/// restricts the calling thread to `cpus`, threads it spawns afterwards inherit the mask
+pub fn pin_current_thread(cpus: &[usize]) -> anyhow::Result<()> {
+    let mut set: libc::cpu_set_t = unsafe { mem::zeroed() };
+    for &cpu in cpus {
+        unsafe { libc::CPU_SET(cpu, &mut set) };
+    }
+
+    if unsafe { libc::sched_setaffinity(0, mem::size_of::<libc::cpu_set_t>(), &set) } != 0 {
+        return Err(std::io::Error::last_os_error())
+            .with_context(|| format!("failed to pin to cpus {cpus:?}"));
+    }
+
+    Ok(())
+}
+
+/// the lowest nice value. on linux it only applies to the calling thread, so this has to run
+/// before the rayon workers are spawned for them to inherit it
+fn raise_priority() -> anyhow::Result<()> {
+    if unsafe { libc::setpriority(libc::PRIO_PROCESS, 0, -20) } != 0 {
+        return Err(std::io::Error::last_os_error())
+            .context("failed to raise the priority, this needs CAP_SYS_NICE or root");
+    }
+
+    Ok(())
+}
+
+/// which cpu the benchmark thread and the rayon workers run on
+pub struct Placement {
+    pub benchmark: Option<usize>,
+    /// worker `i` runs on `workers[i % workers.len()]`
+    pub workers: Option<Vec<usize>>,
+}
+
+impl Placement {
+    /// a pool whose workers sit on the worker cpus. every pool is built from this, so
+    /// `--worker-cpus` and `--isolate` cover all of them
+    pub fn pool_builder(&self) -> rayon::ThreadPoolBuilder {
+        let workers = self.workers.clone();
+
+        rayon::ThreadPoolBuilder::new().start_handler(move |index| {
+            if let Some(cpus) = &workers
+                && let Err(error) = pin_current_thread(&[cpus[index % cpus.len()]])
+            {
+                eprintln!("{}", format!("rayon worker {index}: {error:#}").yellow());
+            }
+        })
+    }
+}
+
+/// checks the requested cpus and raises the priority for `--isolate`. isolating keeps the
+/// workers off the benchmark cpu unless they got their own set
+pub fn place(
+    pin_cpu: Option<usize>,
+    worker_cpus: Option<Vec<usize>>,
+    isolate: bool,
+) -> anyhow::Result<Placement> {
+    let allowed = allowed_cpus()?;
+
+    for cpu in pin_cpu.iter().chain(worker_cpus.iter().flatten()) {
+        if !allowed.contains(cpu) {
+            anyhow::bail!("cpu {cpu} is not available, this process may run on {allowed:?}");
+        }
+    }
+
+    let workers = match (worker_cpus, pin_cpu) {
+        (Some(cpus), _) => Some(cpus),
+        (None, Some(pinned)) if isolate => {
+            let others = allowed
+                .into_iter()
+                .filter(|&cpu| cpu != pinned)
+                .collect::<Vec<_>>();
+
+            // with a single cpu there is nothing to isolate from
+            (!others.is_empty()).then_some(others)
+        }
+        (None, _) => None,
+    };
+
+    if isolate && let Err(error) = raise_priority() {
+        eprintln!("{}", format!("{error:#}").yellow());
+    }
+
+    Ok(Placement {
+        benchmark: pin_cpu,
+        workers,
+    })
+}
+
+fn read_sysfs(path: &str) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|value| value.trim().to_owned())
+}
+
+/// prints where everything runs and whatever sysfs tells about frequency scaling and turbo.
+/// goes to stderr like other diagnostics, so it doesn't mix into output like `inspect --json`
+pub fn report(placement: &Placement) {
+    eprintln!("{}", "=== CPU placement ===".magenta().bold());
+
+    if let Some(cpu) = placement.benchmark {
+        eprintln!("    {}: cpu {}", "Benchmark thread".italic(), cpu.bold());
+    }
+    if let Some(workers) = &placement.workers {
+        eprintln!(
+            "    {}: cpus {:?}",
+            "Rayon workers".italic(),
+            workers.bold()
+        );
+    }
+
+    let priority = unsafe { libc::getpriority(libc::PRIO_PROCESS, 0) };
+    eprintln!("    {}: {}", "Nice".italic(), priority.bold());
+
+    let cpus = placement
+        .benchmark
+        .iter()
+        .chain(placement.workers.iter().flatten())
+        .copied()
+        .collect::<BTreeSet<_>>();
+
+    let mut exposed = false;
+    for cpu in cpus {
+        let cpufreq = format!("/sys/devices/system/cpu/cpu{cpu}/cpufreq");
+        let Some(governor) = read_sysfs(&format!("{cpufreq}/scaling_governor")) else {
+            continue;
+        };
+        exposed = true;
+
+        let megahertz = |file: &str| {
+            read_sysfs(&format!("{cpufreq}/{file}"))
+                .and_then(|khz| khz.parse::<u64>().ok())
+                .map_or_else(|| "?".to_owned(), |khz| (khz / 1000).to_string())
+        };
+
+        let governor = if governor == "performance" {
+            governor.bold().to_string()
+        } else {
+            format!("{governor} (frequency scales with load)")
+                .yellow()
+                .to_string()
+        };
+
+        eprintln!(
+            "    {}: {governor}, {}-{} MHz",
+            format!("cpu {cpu}").italic(),
+            megahertz("scaling_min_freq"),
+            megahertz("scaling_max_freq")
+        );
+    }
+
+    // intel_pstate has its own switch, acpi-cpufreq and amd-pstate use the generic one
+    let turbo = read_sysfs("/sys/devices/system/cpu/intel_pstate/no_turbo")
+        .map(|no_turbo| no_turbo == "0")
+        .or_else(|| read_sysfs("/sys/devices/system/cpu/cpufreq/boost").map(|boost| boost == "1"));
+    match turbo {
+        Some(true) => eprintln!(
+            "    {}: {}",
+            "Turbo".italic(),
+            "enabled, clocks depend on temperature and load".yellow()
+        ),
+        Some(false) => eprintln!("    {}: {}", "Turbo".italic(), "disabled".bold()),
+        None => {}
+    }
+
+    if !exposed && turbo.is_none() {
+        eprintln!(
+            "{}",
+            "    sysfs does not expose frequency scaling here, e.g. inside a vm".dimmed()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cpu_lists() {
+        assert_eq!(parse_cpu_list("2").unwrap().0, [2]);
+        assert_eq!(parse_cpu_list("2-5,8").unwrap().0, [2, 3, 4, 5, 8]);
+    }
+
+    #[test]
+    fn invalid_cpu_lists() {
+        assert_eq!(
+            parse_cpu_list("5-2").unwrap_err(),
+            "`5-2` is an empty range"
+        );
+        assert_eq!(
+            parse_cpu_list("2,x").unwrap_err(),
+            "`x` is not a cpu number"
+        );
+    }
+}
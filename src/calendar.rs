@@ -0,0 +1,103 @@
+//! the advent calendar grid with the stars of every day
+
+use std::{
+    collections::BTreeSet,
+    panic::{AssertUnwindSafe, catch_unwind},
+    path::Path,
+};
+
+use owo_colors::OwoColorize as _;
+
+use advent_of_code::{AoCDate, days_in_year, get_solutions, load_all_input_files, parse_answers};
+
+use crate::stars::{self, Star};
+
+fn render_star(star: Star) -> String {
+    match star {
+        Star::Earned => "★".yellow().bold().to_string(),
+        Star::Unbacked => "★".red().to_string(),
+        Star::Missing => "·".dimmed().to_string(),
+    }
+}
+
+pub fn calendar(year: Option<u16>) -> anyhow::Result<()> {
+    let solutions = get_solutions();
+    let inputs = load_all_input_files(Path::new("./input"))?;
+    let answers = load_all_input_files(Path::new("./answers"))?;
+
+    let years = match year {
+        Some(year) => BTreeSet::from([year]),
+        None => solutions
+            .keys()
+            .chain(answers.keys())
+            .map(|AoCDate(year, _)| *year)
+            .collect(),
+    };
+
+    for year in years {
+        let day_count = days_in_year(year);
+        // five rows of five, the twelve days since 2025 fit three rows of four
+        let columns = if day_count == 25 { 5 } else { 4 };
+
+        let mut earned = 0;
+        let mut unbacked = false;
+        let mut grid = String::new();
+
+        for day in 1..=day_count {
+            let date = AoCDate(year, day);
+            let accepted = answers
+                .get(&date)
+                .map(|file| parse_answers(file))
+                .unwrap_or_default();
+            // only days with an accepted answer are run, a panic does not earn a star either
+            let day_answers = match (solutions.get(&date), inputs.get(&date)) {
+                (Some(solution), Some(input)) if accepted.iter().any(Option::is_some) => {
+                    catch_unwind(AssertUnwindSafe(|| (solution.run_fn)(input.clone())))
+                        .ok()
+                        .map(|result| [result.part1, result.part2])
+                }
+                _ => None,
+            };
+            let day_stars = stars::stars(accepted, day_answers);
+
+            earned += usize::from(stars::count(day_stars));
+            unbacked |= day_stars.contains(&Star::Unbacked);
+
+            let number = format!("{day:>2}");
+            let number = if solutions.contains_key(&date) {
+                number.bold().to_string()
+            } else {
+                number.dimmed().to_string()
+            };
+
+            grid += &format!(
+                "  {number} {}{} ",
+                render_star(day_stars[0]),
+                render_star(day_stars[1])
+            );
+            if usize::from(day) % columns == 0 || day == day_count {
+                grid += "\n";
+            }
+        }
+
+        println!(
+            "{} {}",
+            format!("=== {year} ===").magenta().bold(),
+            format!("{earned}/{} ★", 2 * usize::from(day_count)).yellow()
+        );
+        print!("{grid}");
+
+        if unbacked {
+            println!(
+                "{}",
+                format!(
+                    "  {} has an accepted answer the runner does not reproduce, e.g. no input or another answer",
+                    "★".red()
+                )
+                .dimmed()
+            );
+        }
+    }
+
+    Ok(())
+}
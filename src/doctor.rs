@@ -0,0 +1,243 @@
+//! repository health checks. source checks read `src/`, so `doctor` has to run from the
+//! repository root.
+
+use std::{collections::BTreeSet, fs, path::Path};
+
+use anyhow::Context;
+use owo_colors::OwoColorize as _;
+use regex::Regex;
+
+use advent_of_code::{AoCDate, get_days, get_solutions, load_all_input_files};
+
+struct Finding {
+    date: AoCDate,
+    problem: String,
+    explanation: &'static str,
+}
+
+/// days declared as `pub mod dayDD;` in every `src/yearYYYY/mod.rs`
+fn declared_modules(source: &Path) -> anyhow::Result<BTreeSet<AoCDate>> {
+    let declaration = Regex::new(r"(?m)^pub mod day(\d+);").expect("valid regex");
+    let mut modules = BTreeSet::new();
+
+    for entry in fs::read_dir(source).context("doctor has to run from the repository root")? {
+        let path = entry.context("failed to read entry")?.path();
+
+        let Some(year) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix("year"))
+            .and_then(|year| year.parse().ok())
+        else {
+            continue;
+        };
+
+        let mod_file = fs::read_to_string(path.join("mod.rs"))
+            .with_context(|| format!("failed to read {}", path.join("mod.rs").display()))?;
+
+        for day in declaration.captures_iter(&mod_file) {
+            modules.insert(AoCDate(year, day[1].parse()?));
+        }
+    }
+
+    Ok(modules)
+}
+
+fn source_findings(date: AoCDate, source: &str, findings: &mut Vec<Finding>) {
+    // the attributes in front of a function, `#[ignore]` may come before or after `#[test]`
+    let attributed_fn = Regex::new(r"(?:#\[[^\]]*\]\s*)+fn (\w+)").expect("valid regex");
+    let placeholder_part =
+        Regex::new(r"pub fn (part[12])\([^)]*\) -> u64 \{\s*0\s*(//[^\n]*\s*)*\}")
+            .expect("valid regex");
+
+    for test in attributed_fn.captures_iter(source) {
+        if !test[0].contains("#[test]") || !test[0].contains("#[ignore") {
+            continue;
+        }
+
+        findings.push(Finding {
+            date,
+            problem: format!("test `{}` is ignored", &test[1]),
+            explanation: "ignored tests pass silently, so whatever they check is not verified",
+        });
+    }
+
+    for part in placeholder_part.captures_iter(source) {
+        findings.push(Finding {
+            date,
+            problem: format!("`{}` returns a placeholder 0", &part[1]),
+            explanation: "the runner prints 0 like a real answer, so the part looks solved unless it is listed in `INFO.unfinished_parts`",
+        });
+    }
+}
+
+fn input_findings(date: AoCDate, input: &str, findings: &mut Vec<Finding>) {
+    if input.contains("\r\n") {
+        findings.push(Finding {
+            date,
+            problem: "input has CRLF line endings".to_owned(),
+            explanation: "parsers split on `\\n` and would see a trailing `\\r` on every line",
+        });
+    }
+
+    if !input.ends_with('\n') {
+        findings.push(Finding {
+            date,
+            problem: "input has no trailing newline".to_owned(),
+            explanation: "downloaded inputs end in a newline and some parsers rely on it, e.g. by dropping the final byte",
+        });
+    }
+}
+
+pub fn doctor() -> anyhow::Result<()> {
+    let solutions = get_solutions();
+    let days = get_days()
+        .into_iter()
+        .map(|(date, _)| date)
+        .collect::<BTreeSet<_>>();
+    let modules = declared_modules(Path::new("./src"))?;
+    let inputs = load_all_input_files(Path::new("./input"))?;
+
+    let mut findings = Vec::new();
+
+    for &date in &modules {
+        let AoCDate(year, day) = date;
+
+        if !solutions.contains_key(&date) {
+            findings.push(Finding {
+                date,
+                problem: format!("year{year}::day{day:02} is not registered"),
+                explanation: "no runner mode runs it, add it to `get_solutions` once it works",
+            });
+        }
+
+        if !days.contains(&date) {
+            findings.push(Finding {
+                date,
+                problem: format!("year{year}::day{day:02} is missing from `get_days`"),
+                explanation: "`list` only shows days in `get_days`",
+            });
+        }
+
+        let path = format!("./src/year{year}/day{day:02}.rs");
+        let source = fs::read_to_string(&path).with_context(|| format!("failed to read {path}"))?;
+        source_findings(date, &source, &mut findings);
+    }
+
+    for date in inputs.keys() {
+        if !solutions.contains_key(date) {
+            findings.push(Finding {
+                date: *date,
+                problem: "input has no solution".to_owned(),
+                explanation: "the input is never used, check the file name or register the day",
+            });
+        }
+    }
+
+    for date in solutions.keys() {
+        if !inputs.contains_key(date) {
+            findings.push(Finding {
+                date: *date,
+                problem: "solution has no input".to_owned(),
+                explanation: "`benchmark` skips it and the real input tests are ignored, save the input to `input/yearYYYY/dayDD.txt`",
+            });
+        }
+    }
+
+    for (date, input) in &inputs {
+        input_findings(*date, input, &mut findings);
+    }
+
+    findings.sort_by_key(|finding| finding.date);
+
+    for Finding {
+        date: AoCDate(year, day),
+        problem,
+        explanation,
+    } in &findings
+    {
+        println!(
+            "{} {}",
+            format!("Day {year}-{day}:").yellow().bold(),
+            problem.bold()
+        );
+        println!("    {}", explanation.dimmed());
+    }
+
+    if !findings.is_empty() {
+        anyhow::bail!("doctor found {} problems", findings.len());
+    }
+
+    println!("{}", "No problems found".green().bold());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATE: AoCDate = AoCDate(2024, 1);
+
+    fn problems(check: fn(AoCDate, &str, &mut Vec<Finding>), text: &str) -> Vec<String> {
+        let mut findings = Vec::new();
+        check(DATE, text, &mut findings);
+
+        findings
+            .into_iter()
+            .map(|finding| finding.problem)
+            .collect()
+    }
+
+    #[test]
+    fn ignored_tests_in_either_order() {
+        let source = "
+            #[ignore]
+            #[test]
+            fn before() {}
+
+            #[test]
+            #[ignore = \"slow\"]
+            fn after() {}
+
+            #[test]
+            fn runs() {}
+        ";
+
+        assert_eq!(
+            problems(source_findings, source),
+            ["test `before` is ignored", "test `after` is ignored"]
+        );
+    }
+
+    #[test]
+    fn placeholder_parts() {
+        let source = "
+            pub fn part1(input: &Parsed) -> u64 {
+                input.len() as u64
+            }
+
+            pub fn part2(_input: &Parsed) -> u64 {
+                0 // TODO
+            }
+        ";
+
+        assert_eq!(
+            problems(source_findings, source),
+            ["`part2` returns a placeholder 0"]
+        );
+    }
+
+    #[test]
+    fn input_line_endings() {
+        assert!(problems(input_findings, "1\n2\n").is_empty());
+        assert_eq!(
+            problems(input_findings, "1\r\n2\r\n"),
+            ["input has CRLF line endings"]
+        );
+        assert_eq!(
+            problems(input_findings, "1\n2"),
+            ["input has no trailing newline"]
+        );
+    }
+}
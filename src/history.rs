@@ -0,0 +1,288 @@
+//! `benchmark --record` appends every run to a local history file together with what the
+//! numbers depend on: the commit, the compiler, the `target-cpu` and the machine.
+//! `history` reads it back for a single day.
+
+use std::{
+    collections::BTreeMap,
+    fs::{self, OpenOptions},
+    io::Write as _,
+    path::Path,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Context;
+use owo_colors::OwoColorize as _;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use advent_of_code::{AoCDate, RunResult};
+
+const HISTORY_PATH: &str = "./history/benchmarks.jsonl";
+
+/// a day's total has to change by more than this fraction to be highlighted. single runs of
+/// microsecond days are noisy, so smaller changes are mostly the machine
+const SIGNIFICANT_CHANGE: f64 = 0.2;
+
+const TREND_WIDTH: usize = 30;
+
+/// one line of the history file, written per `benchmark --record` run
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    /// seconds since the unix epoch
+    timestamp: u64,
+    commit: Option<String>,
+    /// whether tracked files had uncommitted changes
+    dirty: bool,
+    rustc: Option<String>,
+    target_cpu: Option<String>,
+    cpu_model: Option<String>,
+    days: Vec<DayEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct DayEntry {
+    year: u16,
+    day: u8,
+    /// nanoseconds per phase, keyed by `Phase::name`
+    phases: BTreeMap<String, u64>,
+    part1: u64,
+    part2: u64,
+}
+
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+}
+
+/// the `-C target-cpu=…` rustflag from `.cargo/config.toml`, if it sets one
+fn target_cpu() -> Option<String> {
+    let config = fs::read_to_string("./.cargo/config.toml").ok()?;
+    let target_cpu = Regex::new(r"target-cpu=([\w-]+)").expect("valid regex");
+
+    Some(target_cpu.captures(&config)?[1].to_owned())
+}
+
+fn cpu_model() -> Option<String> {
+    let cpuinfo = fs::read_to_string("/proc/cpuinfo").ok()?;
+
+    cpuinfo
+        .lines()
+        .find_map(|line| line.strip_prefix("model name")?.split_once(':'))
+        .map(|(_, model)| model.trim().to_owned())
+}
+
+/// the rustc cargo would use, which is the one that built the runner unless the toolchain
+/// changed since
+fn rustc_version() -> Option<String> {
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_owned());
+
+    command_output(&rustc, &["--version"])
+}
+
+pub fn record(results: &[(AoCDate, RunResult)]) -> anyhow::Result<()> {
+    let entry = Entry {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .context("system clock is before 1970")?
+            .as_secs(),
+        commit: command_output("git", &["rev-parse", "HEAD"]),
+        dirty: command_output("git", &["status", "--porcelain", "--untracked-files=no"])
+            .is_some_and(|status| !status.is_empty()),
+        rustc: rustc_version(),
+        target_cpu: target_cpu(),
+        cpu_model: cpu_model(),
+        days: results
+            .iter()
+            .map(|(AoCDate(year, day), result)| DayEntry {
+                year: *year,
+                day: *day,
+                phases: result
+                    .phases
+                    .iter()
+                    .map(|phase| (phase.phase.name().to_owned(), phase.time.as_nanos() as u64))
+                    .collect(),
+                part1: result.part1,
+                part2: result.part2,
+            })
+            .collect(),
+    };
+
+    let path = Path::new(HISTORY_PATH);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    }
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("failed to open {}", path.display()))?;
+    writeln!(file, "{}", serde_json::to_string(&entry)?)
+        .with_context(|| format!("failed to write {}", path.display()))?;
+
+    println!(
+        "Recorded {} days in {}",
+        entry.days.len().bold(),
+        path.display().bold()
+    );
+
+    Ok(())
+}
+
+/// `YYYY-MM-DD HH:MM` in utc, see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn format_timestamp(timestamp: u64) -> String {
+    let (days, seconds) = ((timestamp / 86400) as i64, timestamp % 86400);
+
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60
+    )
+}
+
+pub fn history(AoCDate(year, day): AoCDate) -> anyhow::Result<()> {
+    let path = Path::new(HISTORY_PATH);
+    let history = fs::read_to_string(path).with_context(|| {
+        format!(
+            "failed to read {}, record runs with `benchmark --record`",
+            path.display()
+        )
+    })?;
+
+    let mut runs = Vec::new();
+    for (number, line) in history.lines().enumerate() {
+        let mut entry: Entry = serde_json::from_str(line)
+            .with_context(|| format!("{}:{} is not a history entry", path.display(), number + 1))?;
+
+        if let Some(index) = entry
+            .days
+            .iter()
+            .position(|entry| (entry.year, entry.day) == (year, day))
+        {
+            let day = entry.days.swap_remove(index);
+            runs.push((entry, day));
+        }
+    }
+
+    println!(
+        "{}",
+        format!("=== Day {year}-{day} history ===").magenta().bold()
+    );
+
+    let totals = runs
+        .iter()
+        .map(|(_, day)| Duration::from_nanos(day.phases.values().sum()))
+        .collect::<Vec<_>>();
+    let Some(slowest) = totals.iter().max() else {
+        println!("    {}", "no recorded runs of this day".dimmed());
+        return Ok(());
+    };
+
+    for (index, ((entry, day), total)) in runs.iter().zip(&totals).enumerate() {
+        let previous = index
+            .checked_sub(1)
+            .map(|index| (&runs[index], totals[index]));
+
+        let commit = entry
+            .commit
+            .as_deref()
+            .map_or("unknown", |commit| &commit[..commit.len().min(8)]);
+        let commit = if entry.dirty {
+            format!("{commit}-dirty")
+        } else {
+            commit.to_owned()
+        };
+
+        let bar_width =
+            (total.as_secs_f64() / slowest.as_secs_f64() * TREND_WIDTH as f64).ceil() as usize;
+        let bar = "▇".repeat(bar_width.max(1));
+
+        let change = previous
+            .map(|(_, previous_total)| total.as_secs_f64() / previous_total.as_secs_f64() - 1.0);
+        let change = match change {
+            Some(change) if change <= -SIGNIFICANT_CHANGE => format!("{:+.0}%", change * 100.0)
+                .green()
+                .bold()
+                .to_string(),
+            Some(change) if change >= SIGNIFICANT_CHANGE => {
+                format!("{:+.0}%", change * 100.0).red().bold().to_string()
+            }
+            Some(change) => format!("{:+.0}%", change * 100.0).dimmed().to_string(),
+            None => String::new(),
+        };
+
+        println!(
+            "    {} {:<14} {:>12} {:<width$} {change}",
+            format_timestamp(entry.timestamp).italic(),
+            commit,
+            format!("{total:#?}").bold(),
+            bar,
+            width = TREND_WIDTH
+        );
+
+        let Some(((previous_entry, previous_day), _)) = previous else {
+            continue;
+        };
+
+        // whatever else changed between the runs may explain a jump as well as the code
+        let mut notes = Vec::new();
+        if (previous_day.part1, previous_day.part2) != (day.part1, day.part2) {
+            notes.push("answers changed".red().to_string());
+        }
+        for (what, previous, current) in [
+            ("rustc", &previous_entry.rustc, &entry.rustc),
+            ("target-cpu", &previous_entry.target_cpu, &entry.target_cpu),
+            ("cpu", &previous_entry.cpu_model, &entry.cpu_model),
+        ] {
+            if previous != current {
+                notes.push(
+                    format!("{what} is now {}", current.as_deref().unwrap_or("unknown"))
+                        .dimmed()
+                        .to_string(),
+                );
+            }
+        }
+        if previous_day.phases.keys().ne(day.phases.keys()) {
+            notes.push("phases changed".dimmed().to_string());
+        }
+
+        if !notes.is_empty() {
+            println!("        {}", notes.join(", "));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        // puzzles unlock at midnight in utc-5
+        assert_eq!(format_timestamp(1733029200), "2024-12-01 05:00");
+        assert_eq!(format_timestamp(951868740), "2000-02-29 23:59");
+    }
+}
@@ -0,0 +1,76 @@
+use std::fs;
+
+use anyhow::Context;
+use owo_colors::OwoColorize as _;
+use serde_json::{Map, Value, json};
+
+use advent_of_code::{AoCDate, get_solutions};
+
+/// how much of the parsed value is shown without `--full`, real inputs span thousands of lines
+const PREVIEW_LINES: usize = 40;
+
+pub fn inspect(AoCDate(year, day): AoCDate, as_json: bool, full: bool) -> anyhow::Result<()> {
+    let solutions = get_solutions();
+    let solution = solutions
+        .get(&AoCDate(year, day))
+        .context("no solution registered for day")?;
+
+    let input = fs::read_to_string(format!("./input/year{}/day{:02}.txt", year, day))
+        .context("could not find input file for day")?;
+
+    let inspection = (solution.inspect_fn)(&input);
+
+    if as_json {
+        let stats = inspection
+            .stats
+            .into_iter()
+            .map(|(name, value)| (name.to_owned(), value))
+            .collect::<Map<_, _>>();
+
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&json!({
+                "year": year,
+                "day": day,
+                "stats": stats,
+                "parsed": inspection.json,
+            }))?
+        );
+
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!("=== Inspecting Day {year}-{day} ===")
+            .magenta()
+            .bold()
+    );
+    let line_count = inspection.debug.lines().count();
+    if full || line_count <= PREVIEW_LINES {
+        println!("{}", inspection.debug);
+    } else {
+        for line in inspection.debug.lines().take(PREVIEW_LINES) {
+            println!("{line}");
+        }
+        println!(
+            "{}",
+            format!(
+                "… {} more lines, pass --full to see everything",
+                line_count - PREVIEW_LINES
+            )
+            .dimmed()
+        );
+    }
+
+    // stats go last so they stay visible after a long dump
+    for (name, value) in inspection.stats {
+        let value = match value {
+            Value::String(value) => value,
+            value => value.to_string(),
+        };
+        println!("    {}: {}", name.italic(), value.bold());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,513 @@
+//! advent of code solutions and the runner types around them.
+//!
+//! every day lives in `yearYYYY::dayDD` and exposes `parse`, `part1` and `part2`.
+//! [`get_solutions`] is the registry the runner uses to find them. days where part 2 reuses
+//! part 1's work also expose `part1_with`, which returns a `Context` next to the answer, and
+//! `part2_with`, which takes it.
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+
+pub mod year2023;
+pub mod year2024;
+pub mod year2025;
+
+pub mod memory;
+pub mod plugin;
+pub mod server;
+
+/// `part1_with` and `part2_with` of a day with a context, or `part1` and `part2` handing over
+/// `()` for days without one. expects the day's items in scope
+macro_rules! context_parts {
+    () => {{
+        let part1_with: fn(&Parsed) -> (u64, ()) = |input| (part1(input), ());
+        let part2_with: fn(&Parsed, &()) -> u64 = |input, _| part2(input);
+        (part1_with, part2_with)
+    }};
+    ($context:ident) => {{
+        let part1_with: fn(&Parsed) -> (u64, $context) = part1_with;
+        let part2_with: fn(&Parsed, &$context) -> u64 = part2_with;
+        (part1_with, part2_with)
+    }};
+}
+
+macro_rules! solution {
+    (
+        $year:tt, $day:tt
+        $(, variants: $variants:ident)?
+        $(, solve: $solve:ident)?
+        $(, context: $context:ident)?
+    ) => {{
+        /// the day's `solve`, if it computes both parts in one go
+        #[allow(unreachable_code)]
+        fn solve_fn() -> Option<fn(&str) -> (u64, u64)> {
+            $(return pastey::paste! {
+                Some([<year $year>]::[<day $day>]::$solve as fn(&str) -> (u64, u64))
+            };)?
+            None
+        }
+
+        let run_fn = |data: String| {
+            pastey::paste! {
+                use [<year $year>]::[<day $day>]::*;
+            }
+
+            // coerce to function pointers so they don't get inlined
+            let parse_fn: fn(&str) -> Parsed = std::hint::black_box(parse);
+            let (part1_fn, part2_fn) = std::hint::black_box(context_parts!($($context)?));
+
+            let _day_span =
+                tracing::info_span!(concat!("day ", stringify!($year), "-", stringify!($day)))
+                    .entered();
+
+            if let Some(solve_fn) = std::hint::black_box(solve_fn()) {
+                let ((part1, part2), both_parts) = measure(Phase::BothParts, || solve_fn(&data));
+
+                return RunResult {
+                    phases: vec![both_parts],
+                    part1,
+                    part2,
+                };
+            }
+
+            // building the context is part 1's work, so it is timed with part 1
+            let (input, parse) = measure(Phase::Parse, || parse_fn(&data));
+            let ((part1, context), part1_phase) = measure(Phase::Part1, || part1_fn(&input));
+            let (part2, part2_phase) = measure(Phase::Part2, || part2_fn(&input, &context));
+
+            RunResult {
+                phases: vec![parse, part1_phase, part2_phase],
+                part1,
+                part2,
+            }
+        };
+
+        let phases_fn = |data: &str, runner: &mut dyn PhaseRunner| {
+            pastey::paste! {
+                use [<year $year>]::[<day $day>]::*;
+            }
+
+            if let Some(solve_fn) = std::hint::black_box(solve_fn()) {
+                runner.run_phase(Phase::BothParts, &mut || {
+                    std::hint::black_box(solve_fn(std::hint::black_box(data)));
+                });
+                return;
+            }
+
+            // same coercion as in run_fn so the numbers stay comparable
+            let parse_fn: fn(&str) -> Parsed = std::hint::black_box(parse);
+            let (part1_fn, part2_fn) = std::hint::black_box(context_parts!($($context)?));
+
+            runner.run_phase(Phase::Parse, &mut || {
+                std::hint::black_box(parse_fn(std::hint::black_box(data)));
+            });
+
+            let input = parse_fn(data);
+
+            runner.run_phase(Phase::Part1, &mut || {
+                std::hint::black_box(part1_fn(std::hint::black_box(&input)));
+            });
+
+            let (_, context) = part1_fn(&input);
+
+            runner.run_phase(Phase::Part2, &mut || {
+                std::hint::black_box(part2_fn(
+                    std::hint::black_box(&input),
+                    std::hint::black_box(&context),
+                ));
+            });
+        };
+
+        let part_fn = |data: &str, part: u8| {
+            pastey::paste! {
+                use [<year $year>]::[<day $day>]::*;
+            }
+
+            if let Some(solve_fn) = std::hint::black_box(solve_fn()) {
+                let start = Instant::now();
+                let (part1, part2) = solve_fn(data);
+
+                return PartResult {
+                    phases: vec![(Phase::BothParts, start.elapsed())],
+                    answer: if part == 1 { part1 } else { part2 },
+                };
+            }
+
+            let parse_fn: fn(&str) -> Parsed = std::hint::black_box(parse);
+            let run_part: fn(&Parsed) -> u64 = match part {
+                1 => std::hint::black_box(part1),
+                _ => std::hint::black_box(part2),
+            };
+
+            let parse_start = Instant::now();
+            let input = parse_fn(data);
+            let parse_time = parse_start.elapsed();
+
+            let part_start = Instant::now();
+            let answer = run_part(&input);
+
+            PartResult {
+                phases: vec![
+                    (Phase::Parse, parse_time),
+                    (if part == 1 { Phase::Part1 } else { Phase::Part2 }, part_start.elapsed()),
+                ],
+                answer,
+            }
+        };
+
+        let inspect_fn = |data: &str| {
+            pastey::paste! {
+                use [<year $year>]::[<day $day>]::*;
+            }
+
+            let parsed = parse(data);
+
+            Inspection {
+                debug: format!("{parsed:#?}"),
+                json: serde_json::to_value(&parsed).expect("parsed input serializes to json"),
+                stats: parsed.stats(),
+            }
+        };
+
+        let variants_fn = |data: &str, runner: &mut dyn PhaseRunner| {
+            pastey::paste! {
+                use [<year $year>]::[<day $day>]::*;
+            }
+
+            #[allow(unused_variables)]
+            let variants: &[Variant<Parsed>] = &[];
+            $(let variants: &[Variant<Parsed>] = $variants;)?
+
+            let input = parse(data);
+            let mut results = Vec::new();
+
+            let registered = [
+                Variant {
+                    part: 1,
+                    label: "default",
+                    run: part1,
+                },
+                Variant {
+                    part: 2,
+                    label: "default",
+                    run: part2,
+                },
+            ];
+
+            for part in [1, 2] {
+                if !variants.iter().any(|variant| variant.part == part) {
+                    continue;
+                }
+
+                for variant in registered
+                    .iter()
+                    .chain(variants)
+                    .filter(|variant| variant.part == part)
+                {
+                    let run = std::hint::black_box(variant.run);
+                    let phase = if part == 1 { Phase::Part1 } else { Phase::Part2 };
+
+                    runner.run_phase(phase, &mut || {
+                        std::hint::black_box(run(std::hint::black_box(&input)));
+                    });
+
+                    results.push(VariantResult {
+                        part,
+                        label: variant.label,
+                        answer: run(&input),
+                    });
+                }
+            }
+
+            results
+        };
+
+        (
+            AoCDate($year, $day),
+            Solution {
+                info: pastey::paste! { &[<year $year>]::[<day $day>]::INFO },
+                run_fn,
+                phases_fn,
+                part_fn,
+                inspect_fn,
+                variants_fn,
+            },
+        )
+    }};
+}
+
+pub fn get_solutions() -> HashMap<AoCDate, Solution> {
+    HashMap::from_iter([
+        // 2023
+        solution!(2023, 01),
+        // 2024
+        solution!(2024, 01, context: Context),
+        solution!(2024, 02),
+        solution!(2024, 03),
+        solution!(2024, 04),
+        solution!(2024, 05, variants: VARIANTS),
+        solution!(2024, 06, context: Context),
+        solution!(2024, 07),
+        solution!(2024, 08),
+        // solution!(2024, 09) -- not finished,
+        // 2025
+        solution!(2025, 01, variants: VARIANTS),
+        solution!(2025, 02),
+        solution!(2025, 03, solve: solve),
+    ])
+}
+
+macro_rules! day {
+    ($year:tt, $day:tt) => {
+        (
+            AoCDate($year, $day),
+            pastey::paste! { &[<year $year>]::[<day $day>]::INFO },
+        )
+    };
+}
+
+/// every day module and its metadata, including days that are not in [`get_solutions`] yet
+#[allow(clippy::zero_prefixed_literal)] // days are written like their module names
+pub fn get_days() -> Vec<(AoCDate, &'static DayInfo)> {
+    vec![
+        // 2023
+        day!(2023, 01),
+        // 2024
+        day!(2024, 01),
+        day!(2024, 02),
+        day!(2024, 03),
+        day!(2024, 04),
+        day!(2024, 05),
+        day!(2024, 06),
+        day!(2024, 07),
+        day!(2024, 08),
+        day!(2024, 09),
+        // 2025
+        day!(2025, 01),
+        day!(2025, 02),
+        day!(2025, 03),
+    ]
+}
+
+/// metadata every day declares in its `INFO` const
+pub struct DayInfo {
+    /// puzzle title
+    pub title: &'static str,
+    /// what kind of puzzle it is, e.g. grid, parsing or simulation. days that use rayon are
+    /// tagged `parallel` so `scaling` picks them up
+    pub tags: &'static [&'static str],
+    pub notes: &'static str,
+    /// parts that still return a placeholder answer
+    pub unfinished_parts: &'static [u8],
+}
+
+/// year and day of a puzzle
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AoCDate(pub u16, pub u8);
+
+/// number of puzzles in a year, the event got shorter in 2025
+pub fn days_in_year(year: u16) -> u8 {
+    if year >= 2025 { 12 } else { 25 }
+}
+
+fn load_day_files(
+    path: &Path,
+    year: u16,
+    files: &mut HashMap<AoCDate, String>,
+) -> anyhow::Result<()> {
+    for entry in fs::read_dir(path).context("failed to read year folder")? {
+        let entry = entry.context("failed to read entry")?;
+        let p = entry.path();
+
+        if p.is_file() {
+            let day = p
+                .file_stem()
+                .and_then(|n| n.to_str())
+                .and_then(|n| n.strip_prefix("day"))
+                .and_then(|file_name| file_name.parse().ok())
+                .context("could not extract day from path")?;
+
+            let content = fs::read_to_string(&p)
+                .with_context(|| format!("failed to read {}", p.display()))?;
+
+            files.insert(AoCDate(year, day), content);
+        }
+    }
+    Ok(())
+}
+
+/// reads every `yearYYYY/dayDD.txt` below `base_path`. a missing folder yields no files
+pub fn load_all_input_files(base_path: &Path) -> anyhow::Result<HashMap<AoCDate, String>> {
+    let mut files = HashMap::new();
+
+    if let Ok(year_folders) = fs::read_dir(base_path) {
+        for entry in year_folders {
+            let year_path = entry.context("failed to read file")?.path();
+
+            if year_path.is_dir() {
+                let year = year_path
+                    .file_stem()
+                    .and_then(|file_name| file_name.to_str())
+                    .and_then(|file_name| file_name.strip_prefix("year"))
+                    .and_then(|number| number.parse().ok())
+                    .context("could not extract year from path")?;
+
+                load_day_files(&year_path, year, &mut files)?;
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+/// time and heap usage of one phase of a run
+#[derive(Clone, Copy, Debug)]
+pub struct PhaseResult {
+    pub phase: Phase,
+    pub time: Duration,
+    pub memory: memory::MemoryUsage,
+}
+
+/// runs a phase inside its own trace span and measures it
+fn measure<T>(phase: Phase, routine: impl FnOnce() -> T) -> (T, PhaseResult) {
+    let _span = match phase {
+        Phase::Parse => tracing::info_span!("parse"),
+        Phase::Part1 => tracing::info_span!("part1"),
+        Phase::Part2 => tracing::info_span!("part2"),
+        Phase::BothParts => tracing::info_span!("both_parts"),
+    }
+    .entered();
+
+    let memory = memory::Measurement::start();
+    let start = Instant::now();
+    let value = routine();
+    let time = start.elapsed();
+    let memory = memory.end();
+
+    (
+        value,
+        PhaseResult {
+            phase,
+            time,
+            memory,
+        },
+    )
+}
+
+/// timings and answers of a single run. days with a `solve` function report a single
+/// [`Phase::BothParts`] instead of parse, part 1 and part 2
+pub struct RunResult {
+    pub phases: Vec<PhaseResult>,
+    pub part1: u64,
+    pub part2: u64,
+}
+
+impl RunResult {
+    pub fn total_time(&self) -> Duration {
+        self.phases.iter().map(|phase| phase.time).sum()
+    }
+}
+
+/// entry in the registry, see [`get_solutions`]
+pub struct Solution {
+    pub info: &'static DayInfo,
+    pub run_fn: fn(input: String) -> RunResult,
+    /// hands every phase to the runner as a routine it can call as often as it likes.
+    /// the parts always get the same parsed input and part 2 the context of a single part 1
+    /// run, days with a `solve` only hand over [`Phase::BothParts`]
+    pub phases_fn: fn(input: &str, runner: &mut dyn PhaseRunner),
+    /// parses the input and runs only part 1 or 2, or `solve` for days that have one. part 2
+    /// recomputes what it would get from part 1's context
+    pub part_fn: fn(input: &str, part: u8) -> PartResult,
+    /// only parses the input, for looking at what the parts get to work with
+    pub inspect_fn: fn(input: &str) -> Inspection,
+    /// runs the registered parts next to their [`Variant`]s, every one is timed as a phase of
+    /// `runner` in the order of the results. parts without variants are skipped
+    pub variants_fn: fn(input: &str, runner: &mut dyn PhaseRunner) -> Vec<VariantResult>,
+}
+
+/// an alternative implementation of a part, e.g. a naive reference for an optimized version.
+/// days list theirs in a `VARIANTS` const that is passed to `solution!`
+pub struct Variant<P> {
+    pub part: u8,
+    pub label: &'static str,
+    pub run: fn(&P) -> u64,
+}
+
+/// answer of one variant, the registered part is labeled `default`
+pub struct VariantResult {
+    pub part: u8,
+    pub label: &'static str,
+    pub answer: u64,
+}
+
+/// timings and answer of a single part run with [`Solution::part_fn`]
+pub struct PartResult {
+    pub phases: Vec<(Phase, Duration)>,
+    pub answer: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+    /// a day's `solve`, which does all the work for both parts at once
+    BothParts,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 4] = [Phase::Parse, Phase::Part1, Phase::Part2, Phase::BothParts];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+            Phase::BothParts => "both_parts",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Phase::Parse => "Parse",
+            Phase::Part1 => "Part 1",
+            Phase::Part2 => "Part 2",
+            Phase::BothParts => "Both parts",
+        }
+    }
+}
+
+/// implemented by every day's `Parsed` so `inspect` can show it
+pub trait Inspect: std::fmt::Debug + serde::Serialize {
+    /// summary statistics like counts or dimensions, in display order
+    fn stats(&self) -> Vec<(&'static str, serde_json::Value)> {
+        Vec::new()
+    }
+}
+
+/// a parsed input as seen through [`Inspect`]
+pub struct Inspection {
+    /// pretty printed `Debug` output
+    pub debug: String,
+    pub json: serde_json::Value,
+    pub stats: Vec<(&'static str, serde_json::Value)>,
+}
+
+pub trait PhaseRunner {
+    fn run_phase(&mut self, phase: Phase, routine: &mut dyn FnMut());
+}
+
+/// answer files live in `answers/yearYYYY/dayDD.txt` and hold the accepted answer for part 1
+/// on the first line and for part 2 on the second. unknown answers are left blank.
+pub fn parse_answers(file: &str) -> [Option<u64>; 2] {
+    let mut lines = file.lines().map(|line| line.trim().parse().ok());
+
+    [lines.next().flatten(), lines.next().flatten()]
+}
@@ -0,0 +1,140 @@
+use std::{collections::HashMap, path::Path};
+
+use owo_colors::{OwoColorize as _, Style};
+
+use advent_of_code::{
+    AoCDate, DayInfo, get_days, get_solutions, load_all_input_files, parse_answers,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// registered, with an input and accepted answers for both parts
+    Solved,
+    /// not registered or a part still returns a placeholder
+    Partial,
+    /// finished, but there is no input to run it on
+    InputMissing,
+    /// has an input, but only the examples say the answers are right
+    ExamplesOnly,
+}
+
+impl Status {
+    fn label(self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Partial => "partially solved",
+            Status::InputMissing => "input missing",
+            Status::ExamplesOnly => "examples only",
+        }
+    }
+
+    fn style(self) -> Style {
+        match self {
+            Status::Solved => Style::new().green(),
+            Status::Partial => Style::new().yellow(),
+            Status::InputMissing => Style::new().red(),
+            Status::ExamplesOnly => Style::new().blue(),
+        }
+    }
+}
+
+pub fn status(
+    registered: bool,
+    info: &DayInfo,
+    has_input: bool,
+    answers: [Option<u64>; 2],
+) -> Status {
+    if !registered || !info.unfinished_parts.is_empty() {
+        Status::Partial
+    } else if !has_input {
+        Status::InputMissing
+    } else if answers.iter().any(Option::is_none) {
+        Status::ExamplesOnly
+    } else {
+        Status::Solved
+    }
+}
+
+pub fn list() -> anyhow::Result<()> {
+    let solutions = get_solutions();
+    let inputs = load_all_input_files(Path::new("./input"))?;
+    let answers: HashMap<AoCDate, String> = load_all_input_files(Path::new("./answers"))?;
+
+    let mut days = get_days();
+    days.sort_unstable_by_key(|(date, _)| *date);
+
+    let mut current_year = None;
+    for (date, info) in days {
+        let AoCDate(year, day) = date;
+
+        if current_year != Some(year) {
+            current_year = Some(year);
+            println!("{}", format!("=== {year} ===").magenta().bold());
+        }
+
+        let status = status(
+            solutions.contains_key(&date),
+            info,
+            inputs.contains_key(&date),
+            answers
+                .get(&date)
+                .map(|file| parse_answers(file))
+                .unwrap_or_default(),
+        );
+
+        println!(
+            "    {} {:<24} {:<16} {}",
+            format!("Day {day:02}").bold(),
+            info.title,
+            status.label().style(status.style()),
+            info.tags.join(", ").italic()
+        );
+
+        if !info.notes.is_empty() {
+            println!("           {}", info.notes.dimmed());
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use advent_of_code::year2024;
+
+    use super::*;
+
+    #[test]
+    fn unfinished_days_are_partial() {
+        // day09 is declared but not registered, and its part 2 is a placeholder
+        let info = &year2024::day09::INFO;
+
+        assert_eq!(
+            status(false, info, true, [Some(1), Some(2)]),
+            Status::Partial
+        );
+        assert_eq!(
+            status(true, info, true, [Some(1), Some(2)]),
+            Status::Partial
+        );
+    }
+
+    #[test]
+    fn finished_days() {
+        let info = &year2024::day01::INFO;
+
+        assert_eq!(
+            status(false, info, true, [Some(1), Some(2)]),
+            Status::Partial
+        );
+        assert_eq!(
+            status(true, info, false, [Some(1), Some(2)]),
+            Status::InputMissing
+        );
+        assert_eq!(
+            status(true, info, true, [Some(1), None]),
+            Status::ExamplesOnly
+        );
+        assert_eq!(status(true, info, true, [Some(1), Some(2)]), Status::Solved);
+    }
+}
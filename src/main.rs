@@ -1,215 +1,385 @@
-#![allow(unused)]
-#![allow(dead_code)]
-
 use std::{
     collections::HashMap,
     fs,
-    path::Path,
-    time::{Duration, Instant},
+    panic::{self, AssertUnwindSafe, catch_unwind},
+    path::{Path, PathBuf},
+    time::Duration,
 };
 
+use advent_of_code::{
+    AoCDate, Phase, RunResult, get_solutions, load_all_input_files, memory, plugin,
+    server::SolutionServer,
+};
 use anyhow::Context;
-use clap::{Parser, Subcommand};
+use clap::{Args, Parser, Subcommand};
 use owo_colors::{OwoColorize as _, Style};
 
-pub mod year2023;
-pub mod year2024;
-pub mod year2025;
-
-macro_rules! solution {
-    ($year:tt, $day:tt) => {{
-        let run_fn = |data: String| {
-            pastey::paste! {
-                use [<year $year>]::[<day $day>]::*;
-            }
-
-            // coerce to function pointers so they don't get inlined
-            let parse_fn: fn(&str) -> Parsed = std::hint::black_box(parse);
-            let part1_fn: fn(&Parsed) -> u64 = std::hint::black_box(part1);
-            let part2_fn: fn(&Parsed) -> u64 = std::hint::black_box(part2);
-
-            let parse_start = Instant::now();
-            let input = parse_fn(&data);
-            let parse_end = Instant::now();
-
-            let part1 = part1_fn(&input);
-            let part1_end = Instant::now();
-
-            let part2 = part2_fn(&input);
-            let part2_end = Instant::now();
-
-            RunResult {
-                parse_time: parse_end - parse_start,
-                part1: (part1_end - parse_end, part1),
-                part2: (part2_end - part1_end, part2),
-            }
-        };
-
-        (AoCDate($year, $day), Solution { run_fn })
-    }};
-}
-
-fn get_solutions() -> HashMap<AoCDate, Solution> {
-    HashMap::from_iter([
-        // 2023
-        solution!(2023, 01),
-        // 2024
-        solution!(2024, 01),
-        solution!(2024, 02),
-        solution!(2024, 03),
-        solution!(2024, 04),
-        solution!(2024, 05),
-        solution!(2024, 06),
-        solution!(2024, 07),
-        solution!(2024, 08),
-        // solution!(2024, 09) -- not finished,
-        // 2025
-        solution!(2025, 01),
-        solution!(2025, 02),
-        solution!(2025, 03),
-    ])
+mod affinity;
+mod calendar;
+mod doctor;
+mod history;
+mod inspect;
+mod list;
+mod profile;
+mod report;
+mod scaling;
+mod stars;
+mod timings;
+mod tui;
+mod variants;
+mod verify;
+mod watch;
+
+#[derive(Args, Clone, Debug)]
+struct RunOptions {
+    /// run the solutions from a plugin built with `cargo build --example plugin`
+    #[arg(long)]
+    plugin: Option<PathBuf>,
+    /// report heap allocations per phase, requires building with `--features mem`. a plugin
+    /// brings its own allocator, so its allocations can't be counted
+    #[arg(long, conflicts_with = "plugin")]
+    mem: bool,
+    /// write a chrome trace event file (open it in `about:tracing` or perfetto)
+    #[arg(long)]
+    trace: Option<PathBuf>,
+    /// report cold, first-call and steady-state timings as separate columns
+    #[arg(long, conflicts_with = "plugin")]
+    timings: bool,
 }
 
 #[derive(Clone, Debug, Subcommand)]
 enum Command {
-    Day { year: u16, day: u8 },
-    Benchmark,
+    Day {
+        year: u16,
+        day: u8,
+        #[command(flatten)]
+        options: RunOptions,
+    },
+    Benchmark {
+        #[command(flatten)]
+        options: RunOptions,
+        /// time the registered parts against their variants and check that they agree
+        #[arg(long, conflicts_with_all = ["plugin", "mem", "trace", "timings"])]
+        variants: bool,
+        /// append the timings and answers to the history in history/benchmarks.jsonl
+        #[arg(long, conflicts_with_all = ["variants", "timings"])]
+        record: bool,
+    },
+    /// run a single part in a loop under a sampling profiler and write a flamegraph
+    Profile {
+        year: u16,
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// how long to keep running the part, e.g. `500ms`, `10s` or `1m`
+        #[arg(long, value_parser = profile::parse_duration, default_value = "10s")]
+        duration: Duration,
+        #[arg(long, short, default_value = "flamegraph.svg")]
+        output: PathBuf,
+    },
+    /// rebuild, test and run a day whenever its source, which holds the examples, or input change
+    Watch {
+        year: u16,
+        day: u8,
+        /// build the solutions as a plugin and reload it instead of restarting the runner
+        #[arg(long)]
+        plugin: bool,
+    },
+    /// run every day tagged `parallel` on 1, 2, 4 … --threads threads and report the speedup
+    Scaling {
+        /// runs per thread count, the fastest one is reported
+        #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+    },
+    /// show how a day's time changed over the runs recorded with `benchmark --record`
+    History { year: u16, day: u8 },
+    /// check that the answers do not depend on the thread count or where the input lives
+    Verify {
+        /// run every day on 1, 2, 4 … --threads threads with the input at different offsets.
+        /// without --threads up to the number of cores, but at least 4
+        #[arg(long, required = true)]
+        determinism: bool,
+        /// how often to repeat every combination
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+    },
+    /// show every registered day with its status, title and tags
+    List,
+    /// show the stars of every day in the advent calendar grid
+    Calendar {
+        /// only show this year
+        year: Option<u16>,
+    },
+    /// check for unregistered days, missing inputs, unfinished parts and malformed inputs
+    Doctor,
+    /// only parse a day's input and pretty print the result with summary statistics
+    Inspect {
+        year: u16,
+        day: u8,
+        /// print the parsed value and statistics as json instead
+        #[arg(long)]
+        json: bool,
+        /// print the whole parsed value instead of its first lines
+        #[arg(long)]
+        full: bool,
+    },
+    /// run every day with an input once and write the timings into a report
+    #[command(group = clap::ArgGroup::new("format").required(true).multiple(true))]
+    Report {
+        /// update the table in the marked section of README.md and the star badges in badges/
+        #[arg(long, group = "format")]
+        markdown: bool,
+        /// write a self-contained index.html with timing charts into this directory
+        #[arg(long, group = "format", value_name = "DIR")]
+        html: Option<PathBuf>,
+    },
+    /// run every day in a full screen dashboard that shows the results as they come in
+    Tui,
+    /// answer puzzle inputs posted to a local http api
+    Serve {
+        #[arg(long, default_value_t = 8080)]
+        port: u16,
+    },
 }
 
 #[derive(Parser, Debug)]
 struct Cli {
     #[command(subcommand)]
     command: Command,
+    /// size of the rayon pool the solutions run on, defaults to one thread per core
+    #[arg(long, global = true, value_parser = clap::value_parser!(u16).range(1..))]
+    threads: Option<u16>,
+    /// pin the thread that runs the solutions to this cpu
+    #[arg(long, global = true, value_name = "N")]
+    pin_cpu: Option<usize>,
+    /// pin the rayon workers to these cpus in turn, e.g. `2-5` or `2,4,6`
+    #[arg(long, global = true, value_name = "CPUS", value_parser = affinity::parse_cpu_list)]
+    worker_cpus: Option<affinity::CpuList>,
+    /// raise the priority and keep the rayon workers off the --pin-cpu cpu
+    #[arg(long, global = true)]
+    isolate: bool,
+}
+
+fn print_memory(usage: &memory::MemoryUsage) {
+    println!(
+        "{}",
+        format!(
+            "        {} allocations, {} allocated, {} peak",
+            usage.allocations,
+            memory::format_bytes(usage.bytes_allocated),
+            memory::format_bytes(usage.peak_live_bytes as u64)
+        )
+        .dimmed()
+    );
 }
 
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
-struct AoCDate(u16, u8);
-
-fn load_day_files(
-    path: &Path,
-    year: u16,
-    files: &mut HashMap<AoCDate, String>,
-) -> anyhow::Result<()> {
-    for entry in fs::read_dir(path).context("failed to read year folder")? {
-        let entry = entry.context("failed to read entry")?;
-        let p = entry.path();
-
-        if p.is_file() {
-            let day = p
-                .file_stem()
-                .and_then(|n| n.to_str())
-                .and_then(|n| n.strip_prefix("day"))
-                .and_then(|file_name| file_name.parse().ok())
-                .context("could not extract day from path")?;
-
-            let content = fs::read_to_string(&p)
-                .with_context(|| format!("failed to read {}", p.display()))?;
-
-            files.insert(AoCDate(year, day), content);
+fn print_result(day: AoCDate, result: &RunResult, mem: bool) {
+    println!(
+        "{}",
+        format!("=== Day {}-{} ===", day.0, day.1).magenta().bold()
+    );
+
+    for phase in &result.phases {
+        let value = match phase.phase {
+            Phase::Parse => format!("{:#?}", phase.time),
+            Phase::Part1 => format!("{} (in {:#?})", result.part1, phase.time),
+            Phase::Part2 => format!("{} (in {:#?})", result.part2, phase.time),
+            Phase::BothParts => format!(
+                "{} and {} (in {:#?})",
+                result.part1, result.part2, phase.time
+            ),
+        };
+
+        println!(
+            "{}",
+            format!("    {}: {value}", phase.phase.label().italic()).bold()
+        );
+        if mem {
+            print_memory(&phase.memory);
         }
     }
-    Ok(())
 }
 
-fn load_all_input_files(base_path: &Path) -> anyhow::Result<HashMap<AoCDate, String>> {
-    let mut files = HashMap::new();
+/// runs `f` and returns the message if it panics. the panic hook is silenced meanwhile, so the
+/// message is only shown where the caller puts it
+fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = catch_unwind(AssertUnwindSafe(f));
+    panic::set_hook(hook);
+
+    result.map_err(|panic| {
+        panic
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| panic.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "panicked".to_owned())
+    })
+}
 
-    if let Ok(year_folders) = fs::read_dir(base_path) {
-        for entry in year_folders {
-            let year_path = entry.context("failed to read file")?.path();
+fn main() -> anyhow::Result<()> {
+    let args = Cli::parse();
 
-            if year_path.is_dir() {
-                let year = year_path
-                    .file_stem()
-                    .and_then(|file_name| file_name.to_str())
-                    .and_then(|file_name| file_name.strip_prefix("year"))
-                    .and_then(|number| number.parse().ok())
-                    .context("could not extract year from path")?;
+    // before the pool is built, so the workers inherit the raised priority
+    let placement = affinity::place(
+        args.pin_cpu,
+        args.worker_cpus.clone().map(|cpus| cpus.0),
+        args.isolate,
+    )?;
+    if args.isolate || placement.benchmark.is_some() || placement.workers.is_some() {
+        affinity::report(&placement);
+    }
 
-                load_day_files(&year_path, year, &mut files)?;
+    // the global pool, so every mode honors --threads
+    placement
+        .pool_builder()
+        .num_threads(args.threads.map_or(0, usize::from))
+        .build_global()
+        .context("failed to build the thread pool")?;
+
+    // the solutions run on this thread, which only waits while the workers run the parallel
+    // parts. pinned after the pool is built, so the workers don't inherit its cpu
+    if let Some(cpu) = placement.benchmark {
+        affinity::pin_current_thread(&[cpu])?;
+    }
+
+    run(args, &placement)
+}
+
+fn run(args: Cli, placement: &affinity::Placement) -> anyhow::Result<()> {
+    // the standalone commands return here, what's left runs one day or all of them
+    let (options, day, record) = match &args.command {
+        Command::Watch { year, day, plugin } => {
+            return watch::watch(*year, *day, *plugin, args.threads);
+        }
+        Command::Scaling { runs } => return scaling::scaling(args.threads, *runs, placement),
+        Command::Profile {
+            year,
+            day,
+            part,
+            duration,
+            output,
+        } => return profile::profile(AoCDate(*year, *day), *part, *duration, output),
+        Command::History { year, day } => return history::history(AoCDate(*year, *day)),
+        Command::Verify { runs, .. } => return verify::determinism(args.threads, *runs, placement),
+        Command::List => return list::list(),
+        Command::Calendar { year } => return calendar::calendar(*year),
+        Command::Tui => return tui::tui(args.threads, placement),
+        Command::Doctor => return doctor::doctor(),
+        Command::Inspect {
+            year,
+            day,
+            json,
+            full,
+        } => return inspect::inspect(AoCDate(*year, *day), *json, *full),
+        Command::Report { markdown, html } => return report::report(*markdown, html.clone()),
+        Command::Serve { port } => {
+            let server = SolutionServer::bind(("127.0.0.1", *port))?;
+            if let Some(addr) = server.local_addr() {
+                println!(
+                    "{}",
+                    format!("=== Serving on http://{addr} ===").magenta().bold()
+                );
             }
+            return server.run();
         }
+        Command::Benchmark { variants: true, .. } => {
+            return variants::compare(Path::new("./input"));
+        }
+        Command::Day { year, day, options } => (options, Some(AoCDate(*year, *day)), false),
+        Command::Benchmark {
+            options, record, ..
+        } => (options, None, *record),
+    };
+
+    if options.plugin.is_some() && (args.threads.is_some() || args.worker_cpus.is_some()) {
+        anyhow::bail!(
+            "--threads and --worker-cpus can't configure the plugin's rayon pool, it brings its own copy of rayon"
+        );
     }
 
-    Ok(files)
-}
+    if options.mem && !memory::ENABLED {
+        anyhow::bail!("--mem needs the counting allocator, rebuild with `--features mem`");
+    }
 
-struct RunResult {
-    parse_time: Duration,
-    part1: (Duration, u64),
-    part2: (Duration, u64),
-}
+    // the trace file is written when the guard is dropped at the end of main
+    let _trace_guard = options.trace.as_ref().map(|path| {
+        use tracing_subscriber::layer::SubscriberExt as _;
 
-struct Solution {
-    run_fn: fn(input: String) -> RunResult,
-}
+        let (chrome_layer, guard) = tracing_chrome::ChromeLayerBuilder::new()
+            .file(path)
+            .include_args(true)
+            .build();
+        tracing::subscriber::set_global_default(tracing_subscriber::registry().with(chrome_layer))
+            .expect("no other subscriber is installed");
 
-fn main() -> anyhow::Result<()> {
-    let args = Cli::parse();
+        guard
+    });
 
-    let input_files: HashMap<AoCDate, String> = match args.command {
-        Command::Day { year, day } => HashMap::from_iter([(
+    let input_files: HashMap<AoCDate, String> = match day {
+        Some(AoCDate(year, day)) => HashMap::from_iter([(
             AoCDate(year, day),
             fs::read_to_string(format!("./input/year{}/day{:02}.txt", year, day))
                 .context("could not find input file for day")?,
         )]),
-        Command::Benchmark => load_all_input_files(Path::new("./input"))?,
+        None => load_all_input_files(Path::new("./input"))?,
     };
 
+    let plugin = options
+        .plugin
+        .as_deref()
+        .map(plugin::Plugin::load)
+        .transpose()?;
+
     let solutions = get_solutions();
 
-    let mut results = input_files
-        .into_iter()
-        .filter_map(|(day, file)| {
-            solutions
-                .get(&day)
-                .map(|solution| (day, (solution.run_fn)(file)))
-        })
-        .collect::<Vec<_>>();
+    if options.timings {
+        return timings::timings(
+            input_files
+                .keys()
+                .filter_map(|date| solutions.get(date).map(|solution| (*date, solution)))
+                .collect(),
+        );
+    }
+
+    let (mut results, solution_count) = match &plugin {
+        Some(plugin) => {
+            let dates = plugin.dates();
+            let results = input_files
+                .into_iter()
+                .filter(|(day, _)| dates.contains(day))
+                .map(|(day, file)| plugin.run(day, &file).map(|result| (day, result)))
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            (results, dates.len())
+        }
+        None => {
+            let results = input_files
+                .into_iter()
+                .filter_map(|(day, file)| {
+                    solutions
+                        .get(&day)
+                        .map(|solution| (day, (solution.run_fn)(file)))
+                })
+                .collect::<Vec<_>>();
+
+            (results, solutions.len())
+        }
+    };
 
     results.sort_unstable_by_key(|result| result.0);
 
     let mut total_time_elapsed = Duration::from_nanos(0);
 
     for (day, result) in results.iter() {
-        println!(
-            "{}",
-            format!("=== Day {}-{} ===", day.0, day.1).magenta().bold()
-        );
-        println!(
-            "{}",
-            format!("    {}: {:#?}", "Parse".italic(), result.parse_time).bold()
-        );
-        println!(
-            "{}",
-            format!(
-                "    {}: {} (in {:#?})",
-                "Part 1".italic(),
-                result.part1.1,
-                result.part1.0
-            )
-            .bold()
-        );
-        println!(
-            "{}",
-            format!(
-                "    {}: {} (in {:#?})",
-                "Part 2".italic(),
-                result.part2.1,
-                result.part2.0
-            )
-            .bold()
-        );
+        print_result(*day, result, options.mem);
 
-        total_time_elapsed += result.parse_time + result.part1.0 + result.part2.0;
+        total_time_elapsed += result.total_time();
     }
 
     let mut result_style = Style::new().bold();
 
-    let expected_results = match args.command {
-        Command::Day { .. } => 1,
-        Command::Benchmark => solutions.len(),
-    };
+    let expected_results = if day.is_some() { 1 } else { solution_count };
 
     if results.len() == expected_results {
         result_style = result_style.green();
@@ -220,9 +390,19 @@ fn main() -> anyhow::Result<()> {
     println!(
         "Ran {}/{} solutions in {:#?}",
         results.len().style(result_style),
-        solutions.len().bold(),
+        solution_count.bold(),
         total_time_elapsed,
     );
 
+    if options.mem
+        && let Some(peak_rss) = memory::peak_rss()
+    {
+        println!("Peak RSS: {}", memory::format_bytes(peak_rss).bold());
+    }
+
+    if record {
+        history::record(&results)?;
+    }
+
     Ok(())
 }
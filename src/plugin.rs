@@ -0,0 +1,254 @@
+//! solutions can be built into a `cdylib` (see `examples/plugin.rs`) and loaded at runtime.
+//! the runner and the plugin only talk through the `#[repr(C)]` types below, so a rebuilt
+//! plugin can be swapped into a running process.
+//!
+//! bump [`PLUGIN_ABI_VERSION`] whenever any of these types or the exported symbols change.
+
+use std::{
+    fs,
+    mem::ManuallyDrop,
+    panic::{AssertUnwindSafe, catch_unwind},
+    path::{Path, PathBuf},
+    sync::{
+        OnceLock,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use anyhow::{Context, bail};
+
+use crate::{AoCDate, Phase, PhaseResult, RunResult, get_solutions};
+
+pub const PLUGIN_ABI_VERSION: u32 = 2;
+
+/// `static AOC_PLUGIN_ABI_VERSION: u32`, checked before anything else is touched
+pub const ABI_VERSION_SYMBOL: &[u8] = b"AOC_PLUGIN_ABI_VERSION\0";
+/// `extern "C" fn() -> *const PluginDeclaration`
+pub const ENTRY_SYMBOL: &[u8] = b"aoc_plugin_entry\0";
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct PluginDate {
+    pub year: u16,
+    pub day: u8,
+}
+
+/// marks phases in [`PluginRunResult::phase_nanos`] that did not run
+pub const PHASE_NOT_RUN: u64 = u64::MAX;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct PluginRunResult {
+    /// nanoseconds per phase in [`Phase::ALL`] order
+    pub phase_nanos: [u64; Phase::ALL.len()],
+    pub part1: u64,
+    pub part2: u64,
+}
+
+impl Default for PluginRunResult {
+    fn default() -> Self {
+        Self {
+            phase_nanos: [PHASE_NOT_RUN; Phase::ALL.len()],
+            part1: 0,
+            part2: 0,
+        }
+    }
+}
+
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PluginStatus {
+    Ok = 0,
+    UnknownDay = 1,
+    InvalidInput = 2,
+    Panicked = 3,
+}
+
+pub type PluginRunFn = unsafe extern "C" fn(
+    date: PluginDate,
+    input: *const u8,
+    input_len: usize,
+    result: *mut PluginRunResult,
+) -> PluginStatus;
+
+#[repr(C)]
+pub struct PluginDeclaration {
+    pub solution_count: usize,
+    pub solutions: *const PluginDate,
+    pub run: PluginRunFn,
+}
+
+// the declaration only points to leaked, immutable data
+unsafe impl Sync for PluginDeclaration {}
+unsafe impl Send for PluginDeclaration {}
+
+/// plugin side: describes every solution in the registry
+pub fn declaration() -> &'static PluginDeclaration {
+    static DECLARATION: OnceLock<PluginDeclaration> = OnceLock::new();
+
+    DECLARATION.get_or_init(|| {
+        let mut dates = get_solutions().into_keys().collect::<Vec<_>>();
+        dates.sort_unstable();
+
+        let dates = dates
+            .into_iter()
+            .map(|AoCDate(year, day)| PluginDate { year, day })
+            .collect::<Vec<_>>()
+            .leak();
+
+        PluginDeclaration {
+            solution_count: dates.len(),
+            solutions: dates.as_ptr(),
+            run: run_solution,
+        }
+    })
+}
+
+unsafe extern "C" fn run_solution(
+    date: PluginDate,
+    input: *const u8,
+    input_len: usize,
+    result: *mut PluginRunResult,
+) -> PluginStatus {
+    let input = unsafe { std::slice::from_raw_parts(input, input_len) };
+    let Ok(input) = std::str::from_utf8(input) else {
+        return PluginStatus::InvalidInput;
+    };
+
+    // panics must not unwind into the runner
+    let run = catch_unwind(AssertUnwindSafe(|| {
+        get_solutions()
+            .get(&AoCDate(date.year, date.day))
+            .map(|solution| (solution.run_fn)(input.to_owned()))
+    }));
+
+    match run {
+        Ok(Some(run_result)) => {
+            let mut phase_nanos = [PHASE_NOT_RUN; Phase::ALL.len()];
+            for phase in &run_result.phases {
+                phase_nanos[phase.phase as usize] = phase.time.as_nanos() as u64;
+            }
+
+            unsafe {
+                result.write(PluginRunResult {
+                    phase_nanos,
+                    part1: run_result.part1,
+                    part2: run_result.part2,
+                })
+            };
+            PluginStatus::Ok
+        }
+        Ok(None) => PluginStatus::UnknownDay,
+        Err(_) => PluginStatus::Panicked,
+    }
+}
+
+/// runner side: a loaded plugin
+pub struct Plugin {
+    // plugins are never unloaded: their rayon workers and thread locals may still reference
+    // code inside the library, so each reload maps a fresh copy and leaks the old one
+    _library: ManuallyDrop<libloading::Library>,
+    declaration: &'static PluginDeclaration,
+    pub path: PathBuf,
+}
+
+impl Plugin {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        static LOAD_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+        // the dynamic loader caches libraries by path, copy the plugin so a rebuilt
+        // library at the same path is actually loaded again
+        let copy = std::env::temp_dir().join(format!(
+            "aoc-plugin-{}-{}.so",
+            std::process::id(),
+            LOAD_COUNT.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::copy(path, &copy)
+            .with_context(|| format!("failed to copy plugin {}", path.display()))?;
+
+        let library = unsafe { libloading::Library::new(&copy) };
+        // the mapping stays valid after the file is gone
+        let _ = fs::remove_file(&copy);
+        let library =
+            library.with_context(|| format!("failed to load plugin {}", path.display()))?;
+
+        let abi_version = unsafe {
+            **library
+                .get::<*const u32>(ABI_VERSION_SYMBOL)
+                .with_context(|| format!("{} is not an advent of code plugin", path.display()))?
+        };
+
+        if abi_version != PLUGIN_ABI_VERSION {
+            bail!(
+                "plugin {} was built for ABI version {abi_version}, but this runner expects version {PLUGIN_ABI_VERSION}. rebuild the plugin",
+                path.display()
+            );
+        }
+
+        let declaration = unsafe {
+            let entry = library
+                .get::<unsafe extern "C" fn() -> *const PluginDeclaration>(ENTRY_SYMBOL)
+                .context("plugin has no entry symbol")?;
+            entry()
+                .as_ref()
+                .context("plugin entry returned a null declaration")?
+        };
+
+        Ok(Self {
+            _library: ManuallyDrop::new(library),
+            declaration,
+            path: path.to_owned(),
+        })
+    }
+
+    pub fn dates(&self) -> Vec<AoCDate> {
+        let solutions = unsafe {
+            std::slice::from_raw_parts(self.declaration.solutions, self.declaration.solution_count)
+        };
+
+        solutions
+            .iter()
+            .map(|date| AoCDate(date.year, date.day))
+            .collect()
+    }
+
+    pub fn run(&self, date: AoCDate, input: &str) -> anyhow::Result<RunResult> {
+        let mut result = PluginRunResult::default();
+
+        let status = unsafe {
+            (self.declaration.run)(
+                PluginDate {
+                    year: date.0,
+                    day: date.1,
+                },
+                input.as_ptr(),
+                input.len(),
+                &mut result,
+            )
+        };
+
+        match status {
+            PluginStatus::Ok => Ok(RunResult {
+                phases: Phase::ALL
+                    .into_iter()
+                    .zip(result.phase_nanos)
+                    .filter(|(_, nanos)| *nanos != PHASE_NOT_RUN)
+                    .map(|(phase, nanos)| PhaseResult {
+                        phase,
+                        time: Duration::from_nanos(nanos),
+                        // the plugin brings its own allocator, which the runner can't observe
+                        memory: Default::default(),
+                    })
+                    .collect(),
+                part1: result.part1,
+                part2: result.part2,
+            }),
+            PluginStatus::UnknownDay => {
+                bail!("plugin does not contain day {}-{}", date.0, date.1)
+            }
+            PluginStatus::InvalidInput => bail!("input is not valid utf-8"),
+            PluginStatus::Panicked => bail!("solution for day {}-{} panicked", date.0, date.1),
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use std::{
+    fs::{self, File},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use owo_colors::OwoColorize as _;
+
+use advent_of_code::{AoCDate, Phase, PhaseRunner, get_solutions};
+
+/// samples per second, high enough to see short-lived frames in microsecond sized parts
+const SAMPLE_FREQUENCY: i32 = 997;
+
+/// parses durations like `500ms`, `10s` or `2m`
+pub fn parse_duration(value: &str) -> Result<Duration, String> {
+    let split_at = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split_at);
+
+    let amount: f64 = amount
+        .parse()
+        .map_err(|_| format!("invalid duration `{value}`"))?;
+
+    let seconds = match unit {
+        "ms" => amount / 1000.0,
+        "s" | "" => amount,
+        "m" => amount * 60.0,
+        _ => return Err(format!("unknown duration unit `{unit}`, use ms, s or m")),
+    };
+
+    Duration::try_from_secs_f64(seconds).map_err(|_| format!("duration `{value}` is too long"))
+}
+
+struct ProfileRunner {
+    phase: Phase,
+    duration: Duration,
+    run: Option<(
+        Phase,
+        pprof::Result<pprof::ProfilerGuard<'static>>,
+        usize,
+        Duration,
+    )>,
+}
+
+impl PhaseRunner for ProfileRunner {
+    fn run_phase(&mut self, phase: Phase, routine: &mut dyn FnMut()) {
+        // a `solve` cannot run one part alone, so it is profiled as a whole
+        if phase != self.phase && phase != Phase::BothParts {
+            return;
+        }
+
+        let guard = pprof::ProfilerGuardBuilder::default()
+            .frequency(SAMPLE_FREQUENCY)
+            .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+            .build();
+
+        let start = Instant::now();
+        let mut iterations = 0;
+        while start.elapsed() < self.duration {
+            routine();
+            iterations += 1;
+        }
+
+        self.run = Some((phase, guard, iterations, start.elapsed()));
+    }
+}
+
+pub fn profile(
+    AoCDate(year, day): AoCDate,
+    part: u8,
+    duration: Duration,
+    output: &Path,
+) -> anyhow::Result<()> {
+    let solutions = get_solutions();
+    let solution = solutions
+        .get(&AoCDate(year, day))
+        .context("no solution registered for day")?;
+
+    let input = fs::read_to_string(format!("./input/year{}/day{:02}.txt", year, day))
+        .context("could not find input file for day")?;
+
+    println!(
+        "{}",
+        format!("=== Profiling Day {year}-{day} Part {part} for {duration:#?} ===")
+            .magenta()
+            .bold()
+    );
+
+    let phase = match part {
+        1 => Phase::Part1,
+        _ => Phase::Part2,
+    };
+
+    let mut runner = ProfileRunner {
+        phase,
+        duration,
+        run: None,
+    };
+    (solution.phases_fn)(&input, &mut runner);
+
+    let (phase, guard, iterations, elapsed) = runner.run.context("solution did not run")?;
+    let guard = guard.context("failed to start the profiler")?;
+
+    let report = guard
+        .report()
+        .build()
+        .context("failed to build the profile report")?;
+    drop(guard);
+
+    let samples: isize = report.data.values().sum();
+
+    let mut options = pprof::flamegraph::Options::default();
+    options.title = format!("Day {year}-{day} {}", phase.label());
+
+    let file =
+        File::create(output).with_context(|| format!("failed to create {}", output.display()))?;
+    report
+        .flamegraph_with_options(file, &mut options)
+        .context("failed to write flamegraph")?;
+
+    if phase == Phase::BothParts {
+        println!(
+            "{}",
+            "    the day solves both parts at once, the profile covers both".dimmed()
+        );
+    }
+    println!(
+        "    {}: {iterations} in {elapsed:#?} ({:#?} each)",
+        "Iterations".italic(),
+        elapsed.div_f64(iterations.max(1) as f64)
+    );
+    println!("    {}: {samples}", "Samples".italic());
+    println!("    {}: {}", "Flamegraph".italic(), output.display().bold());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durations() {
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("10s"), Ok(Duration::from_secs(10)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration("1.5"), Ok(Duration::from_millis(1500)));
+    }
+
+    #[test]
+    fn invalid_durations() {
+        assert_eq!(
+            parse_duration("1e3"),
+            Err("unknown duration unit `e3`, use ms, s or m".to_owned())
+        );
+        assert_eq!(parse_duration(""), Err("invalid duration ``".to_owned()));
+        assert_eq!(
+            parse_duration("100000000000000000000s"),
+            Err("duration `100000000000000000000s` is too long".to_owned())
+        );
+    }
+}
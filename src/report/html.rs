@@ -0,0 +1,283 @@
+//! a single html file with inline svg charts, so it works offline and can be archived as is
+
+use std::{collections::BTreeMap, fmt::Write as _, fs, path::Path, time::Duration};
+
+use anyhow::Context;
+use owo_colors::OwoColorize as _;
+
+use advent_of_code::{AoCDate, Phase};
+
+use super::DayReport;
+
+const LABEL_WIDTH: f64 = 90.0;
+const PLOT_WIDTH: f64 = 640.0;
+const ROW_HEIGHT: f64 = 20.0;
+const AXIS_HEIGHT: f64 = 24.0;
+
+/// colors of the phases in the order of [`Phase::ALL`]
+const COLORS: [&str; Phase::ALL.len()] = ["#8da0cb", "#66c2a5", "#fc8d62", "#e78ac3"];
+
+#[derive(Clone, Copy)]
+enum Scale {
+    Linear,
+    /// decades of nanoseconds, so microsecond and second days both get a readable bar
+    Log,
+}
+
+struct Bar {
+    label: String,
+    times: [Duration; Phase::ALL.len()],
+}
+
+/// maps a time to an x offset in the plot area
+struct Axis {
+    scale: Scale,
+    min: f64,
+    max: f64,
+}
+
+/// log scale exponents stay within 1ns and 10^15ns (about 11 days)
+const MAX_EXPONENT: f64 = 15.0;
+
+impl Axis {
+    /// `None` when there is no time to plot, e.g. without any inputs
+    fn new(scale: Scale, bars: &[Bar]) -> Option<Axis> {
+        // the smallest phase sets the lower end of the log scale, not the smallest total
+        let smallest = bars
+            .iter()
+            .flat_map(|bar| bar.times)
+            .filter(|time| !time.is_zero())
+            .map(|time| time.as_nanos() as f64)
+            .reduce(f64::min)?;
+        let largest = bars
+            .iter()
+            .map(|bar| bar.times.iter().sum::<Duration>().as_nanos() as f64)
+            .fold(smallest, f64::max);
+
+        Some(match scale {
+            Scale::Linear => Axis {
+                scale,
+                min: 0.0,
+                max: largest,
+            },
+            Scale::Log => {
+                let min = smallest.log10().floor().clamp(0.0, MAX_EXPONENT - 1.0);
+                let max = largest.log10().ceil().clamp(min + 1.0, MAX_EXPONENT);
+
+                Axis { scale, min, max }
+            }
+        })
+    }
+
+    fn x(&self, nanos: f64) -> f64 {
+        let position = match self.scale {
+            Scale::Linear => nanos / self.max,
+            Scale::Log => (nanos.max(1.0).log10() - self.min) / (self.max - self.min),
+        };
+
+        LABEL_WIDTH + position.clamp(0.0, 1.0) * PLOT_WIDTH
+    }
+
+    /// tick positions in nanoseconds
+    fn ticks(&self) -> Vec<f64> {
+        match self.scale {
+            Scale::Linear => (0..=4).map(|i| self.max * i as f64 / 4.0).collect(),
+            Scale::Log => (self.min as i32..=self.max as i32)
+                .map(|exponent| 10f64.powi(exponent))
+                .collect(),
+        }
+    }
+}
+
+/// horizontal bars with one segment per phase. on the log scale the segments end where their
+/// cumulative time ends, so the bar length is still the total
+fn stacked_bars(bars: &[Bar], scale: Scale) -> String {
+    let Some(axis) = Axis::new(scale, bars) else {
+        return "<p>nothing to plot, there are no timings</p>".to_owned();
+    };
+    let height = bars.len() as f64 * ROW_HEIGHT + AXIS_HEIGHT;
+    let width = LABEL_WIDTH + PLOT_WIDTH + 60.0;
+
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" font-size="11">"#
+    );
+
+    for tick in axis.ticks() {
+        let x = axis.x(tick);
+        let label = format!("{:.0?}", Duration::from_nanos(tick as u64));
+        let _ = write!(
+            svg,
+            r##"<line x1="{x:.1}" x2="{x:.1}" y1="0" y2="{}" stroke="#ddd"/><text x="{x:.1}" y="{}" text-anchor="middle" fill="#666">{label}</text>"##,
+            height - AXIS_HEIGHT,
+            height - 8.0
+        );
+    }
+
+    for (row, bar) in bars.iter().enumerate() {
+        let y = row as f64 * ROW_HEIGHT;
+        let _ = write!(
+            svg,
+            r#"<text x="{}" y="{}" text-anchor="end">{}</text>"#,
+            LABEL_WIDTH - 6.0,
+            y + 14.0,
+            bar.label
+        );
+
+        let mut elapsed = 0.0;
+        let mut start = LABEL_WIDTH;
+        for (phase, time) in Phase::ALL.into_iter().zip(bar.times) {
+            if time.is_zero() {
+                continue;
+            }
+
+            elapsed += time.as_nanos() as f64;
+            let end = axis.x(elapsed);
+            let _ = write!(
+                svg,
+                r#"<rect x="{start:.1}" y="{}" width="{:.1}" height="{}" fill="{}"><title>{} {}: {time:#?}</title></rect>"#,
+                y + 3.0,
+                (end - start).max(0.5),
+                ROW_HEIGHT - 6.0,
+                COLORS[phase as usize],
+                bar.label,
+                phase.label()
+            );
+            start = end;
+        }
+
+        let _ = write!(
+            svg,
+            r##"<text x="{:.1}" y="{}" fill="#666">{:.1?}</text>"##,
+            start + 4.0,
+            y + 14.0,
+            Duration::from_nanos(elapsed as u64)
+        );
+    }
+
+    svg + "</svg>"
+}
+
+fn legend() -> String {
+    Phase::ALL
+        .into_iter()
+        .map(|phase| {
+            format!(
+                r#"<span><svg width="10" height="10"><rect width="10" height="10" fill="{}"/></svg> {}</span>"#,
+                COLORS[phase as usize],
+                phase.label()
+            )
+        })
+        .collect()
+}
+
+fn page(days: &[DayReport]) -> String {
+    let per_day = days
+        .iter()
+        .map(|report| {
+            let AoCDate(year, day) = report.date;
+            Bar {
+                label: format!("{year}-{day:02}"),
+                times: report.phase_times(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut years: BTreeMap<u16, [Duration; Phase::ALL.len()]> = BTreeMap::new();
+    for report in days {
+        let totals = years.entry(report.date.0).or_default();
+        for (total, time) in totals.iter_mut().zip(report.phase_times()) {
+            *total += time;
+        }
+    }
+    let per_year = years
+        .into_iter()
+        .map(|(year, times)| Bar {
+            label: year.to_string(),
+            times,
+        })
+        .collect::<Vec<_>>();
+
+    let build = if cfg!(debug_assertions) {
+        "debug build"
+    } else {
+        "release build"
+    };
+
+    format!(
+        r#"<!doctype html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>advent of code timings</title>
+<style>
+body {{ font-family: system-ui, sans-serif; margin: 2em; color: #222; }}
+.legend span {{ margin-right: 1.5em; }}
+svg text {{ font-family: system-ui, sans-serif; }}
+</style>
+</head>
+<body>
+<h1>advent of code timings</h1>
+<p>{} days, one run each, {build}</p>
+<p class="legend">{}</p>
+<h2>per day</h2>
+{}
+<h2>per year</h2>
+{}
+<h2>per day, log scale</h2>
+<p>every gridline is ten times the one before, segments end where their running total ends</p>
+{}
+</body>
+</html>
+"#,
+        days.len(),
+        legend(),
+        stacked_bars(&per_day, Scale::Linear),
+        stacked_bars(&per_year, Scale::Linear),
+        stacked_bars(&per_day, Scale::Log)
+    )
+}
+
+pub fn write(days: &[DayReport], dir: &Path) -> anyhow::Result<()> {
+    fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+
+    let path = dir.join("index.html");
+    fs::write(&path, page(days)).with_context(|| format!("failed to write {}", path.display()))?;
+
+    println!(
+        "Wrote {} days to {}",
+        days.len().bold(),
+        path.display().bold()
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_without_days() {
+        let page = page(&[]);
+
+        assert!(page.contains("0 days"));
+        assert!(page.contains("nothing to plot"));
+    }
+
+    #[test]
+    fn log_axis_stays_finite() {
+        let bars = [Bar {
+            label: "2024-01".to_owned(),
+            times: [
+                Duration::from_nanos(1),
+                Duration::ZERO,
+                Duration::ZERO,
+                Duration::ZERO,
+            ],
+        }];
+
+        let axis = Axis::new(Scale::Log, &bars).expect("a time to plot");
+        assert_eq!((axis.min, axis.max), (0.0, 1.0));
+        assert_eq!(axis.ticks().len(), 2);
+    }
+}
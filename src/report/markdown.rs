@@ -0,0 +1,228 @@
+//! the benchmark table in `README.md` and a star badge per year
+
+use std::{collections::BTreeMap, fs, path::Path, time::Duration};
+
+use anyhow::Context;
+use owo_colors::OwoColorize as _;
+
+use advent_of_code::{AoCDate, Phase, days_in_year, get_solutions};
+
+use super::DayReport;
+
+const SECTION_START: &str = "<!-- report:start -->";
+const SECTION_END: &str = "<!-- report:end -->";
+
+/// parse, part 1, part 2 and the total, days with a `solve` only add to the total
+#[derive(Default)]
+struct Row([Duration; 4]);
+
+impl Row {
+    fn add(&mut self, report: &DayReport) {
+        let [parse, part1, part2, both_parts] = report.phase_times();
+
+        self.0[0] += parse;
+        self.0[1] += part1;
+        self.0[2] += part2;
+        self.0[3] += parse + part1 + part2 + both_parts;
+    }
+}
+
+/// phases a day does not have are left out instead of showing up as 0ns
+fn cell(time: Duration) -> String {
+    if time.is_zero() {
+        "–".to_owned()
+    } else {
+        format!("{time:.1?}")
+    }
+}
+
+fn table(days: &[DayReport]) -> String {
+    let mut years: BTreeMap<u16, Vec<&DayReport>> = BTreeMap::new();
+    for report in days {
+        years.entry(report.date.0).or_default().push(report);
+    }
+
+    let mut table = String::from(
+        "| Year | Day | Parse | Part 1 | Part 2 | Total |\n\
+         | ---: | --: | ----: | -----: | -----: | ----: |\n",
+    );
+
+    for (year, days) in years {
+        let mut subtotal = Row::default();
+
+        for report in days {
+            let AoCDate(_, day) = report.date;
+            let mut row = Row::default();
+            row.add(report);
+            subtotal.add(report);
+
+            let [parse, part1, part2, mut total] = row.0.map(cell);
+            if !report.phase_times()[Phase::BothParts as usize].is_zero() {
+                total += " (both parts)";
+            }
+            table += &format!("| {year} | {day} | {parse} | {part1} | {part2} | {total} |\n");
+        }
+
+        let [parse, part1, part2, total] = subtotal.0.map(cell);
+        table +=
+            &format!("| **{year}** | | **{parse}** | **{part1}** | **{part2}** | **{total}** |\n");
+    }
+
+    table
+}
+
+/// a flat shields style badge, the text widths are estimated from the character count
+fn badge(label: &str, value: &str, color: &str) -> String {
+    let text_width = |text: &str| text.chars().count() * 7 + 10;
+    let (label_width, value_width) = (text_width(label), text_width(value));
+    let width = label_width + value_width;
+
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="20" role="img" aria-label="{label}: {value}">
+  <title>{label}: {value}</title>
+  <clipPath id="r"><rect width="{width}" height="20" rx="3" fill="#fff"/></clipPath>
+  <g clip-path="url(#r)">
+    <rect width="{label_width}" height="20" fill="#555"/>
+    <rect x="{label_width}" width="{value_width}" height="20" fill="{color}"/>
+  </g>
+  <g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11">
+    <text x="{}" y="14">{label}</text>
+    <text x="{}" y="14">{value}</text>
+  </g>
+</svg>
+"##,
+        label_width / 2,
+        label_width + value_width / 2
+    )
+}
+
+/// replaces everything between the section markers, the markers themselves stay
+fn replace_section(readme: &str, section: &str) -> anyhow::Result<String> {
+    let (before, rest) = readme
+        .split_once(SECTION_START)
+        .with_context(|| format!("README.md has no `{SECTION_START}` marker"))?;
+    let (_, after) = rest
+        .split_once(SECTION_END)
+        .with_context(|| format!("README.md has no `{SECTION_END}` marker"))?;
+
+    Ok(format!(
+        "{before}{SECTION_START}\n{section}{SECTION_END}{after}"
+    ))
+}
+
+pub fn write(days: &[DayReport], readme_path: &Path, badge_dir: &Path) -> anyhow::Result<()> {
+    let readme = fs::read_to_string(readme_path)
+        .with_context(|| format!("failed to read {}", readme_path.display()))?;
+
+    let mut stars: BTreeMap<u16, u32> = get_solutions()
+        .into_keys()
+        .map(|AoCDate(year, _)| (year, 0))
+        .collect();
+    for report in days {
+        *stars.entry(report.date.0).or_default() += u32::from(report.stars);
+    }
+
+    fs::create_dir_all(badge_dir)
+        .with_context(|| format!("failed to create {}", badge_dir.display()))?;
+
+    let mut badges = Vec::new();
+    for (&year, &count) in &stars {
+        let max = 2 * u32::from(days_in_year(year));
+        let color = match count {
+            0 => "#9f9f9f",
+            count if count == max => "#4c1",
+            _ => "#dfb317",
+        };
+
+        let path = badge_dir.join(format!("stars-{year}.svg"));
+        fs::write(
+            &path,
+            badge(&year.to_string(), &format!("{count}/{max} ★"), color),
+        )
+        .with_context(|| format!("failed to write {}", path.display()))?;
+        badges.push(format!("![{year}: {count} stars]({})", path.display()));
+    }
+
+    let section = format!(
+        "{}\n\n{}\n<sub>generated with `cargo run --release -- report --markdown`</sub>\n",
+        badges.join(" "),
+        table(days)
+    );
+    fs::write(readme_path, replace_section(&readme, &section)?)
+        .with_context(|| format!("failed to write {}", readme_path.display()))?;
+
+    println!(
+        "Wrote {} days to {} and {} badges to {}",
+        days.len().bold(),
+        readme_path.display().bold(),
+        stars.len().bold(),
+        badge_dir.display().bold()
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use advent_of_code::{PhaseResult, RunResult};
+
+    use super::*;
+
+    fn report(date: AoCDate, phases: &[(Phase, u64)]) -> DayReport {
+        DayReport {
+            date,
+            result: RunResult {
+                phases: phases
+                    .iter()
+                    .map(|&(phase, micros)| PhaseResult {
+                        phase,
+                        time: Duration::from_micros(micros),
+                        memory: Default::default(),
+                    })
+                    .collect(),
+                part1: 0,
+                part2: 0,
+            },
+            stars: 0,
+        }
+    }
+
+    #[test]
+    fn replaces_only_between_the_markers() {
+        let readme = format!("intro\n{SECTION_START}\nold\n{SECTION_END}\noutro\n");
+
+        assert_eq!(
+            replace_section(&readme, "new\n").unwrap(),
+            format!("intro\n{SECTION_START}\nnew\n{SECTION_END}\noutro\n")
+        );
+    }
+
+    #[test]
+    fn needs_both_markers() {
+        assert!(replace_section("no markers", "new\n").is_err());
+        assert!(replace_section(&format!("{SECTION_START} but no end"), "new\n").is_err());
+    }
+
+    #[test]
+    fn table_rows_and_subtotals() {
+        let days = [
+            report(
+                AoCDate(2024, 1),
+                &[(Phase::Parse, 1), (Phase::Part1, 2), (Phase::Part2, 3)],
+            ),
+            report(AoCDate(2024, 2), &[(Phase::BothParts, 10)]),
+        ];
+
+        let table = table(&days);
+        let rows = table.lines().skip(2).collect::<Vec<_>>();
+
+        assert_eq!(
+            rows,
+            [
+                "| 2024 | 1 | 1.0µs | 2.0µs | 3.0µs | 6.0µs |",
+                "| 2024 | 2 | – | – | – | 10.0µs (both parts) |",
+                "| **2024** | | **1.0µs** | **2.0µs** | **3.0µs** | **16.0µs** |",
+            ]
+        );
+    }
+}
@@ -0,0 +1,98 @@
+//! `report` runs every day that has an input once and writes the results somewhere more
+//! permanent than the terminal.
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use owo_colors::OwoColorize as _;
+
+use advent_of_code::{
+    AoCDate, Phase, RunResult, get_solutions, load_all_input_files, parse_answers,
+};
+
+use crate::{catch_panic, stars};
+
+mod html;
+mod markdown;
+
+pub struct DayReport {
+    pub date: AoCDate,
+    pub result: RunResult,
+    /// parts whose answer matches the accepted one in `answers/`
+    pub stars: u8,
+}
+
+impl DayReport {
+    /// time per phase in the order of [`Phase::ALL`], phases the day does not have are zero
+    pub fn phase_times(&self) -> [Duration; Phase::ALL.len()] {
+        let mut times = [Duration::ZERO; Phase::ALL.len()];
+        for phase in &self.result.phases {
+            times[phase.phase as usize] += phase.time;
+        }
+        times
+    }
+}
+
+fn run_days() -> anyhow::Result<Vec<DayReport>> {
+    let inputs = load_all_input_files(Path::new("./input"))?;
+    let answers: HashMap<AoCDate, String> = load_all_input_files(Path::new("./answers"))?;
+
+    let mut solutions = get_solutions().into_iter().collect::<Vec<_>>();
+    solutions.sort_unstable_by_key(|(date, _)| *date);
+
+    Ok(solutions
+        .into_iter()
+        .filter_map(|(date, solution)| {
+            let input = inputs.get(&date)?;
+            // a day that panics earns no star, like in `calendar`, and has no timings to show
+            let result = match catch_panic(|| (solution.run_fn)(input.clone())) {
+                Ok(result) => result,
+                Err(message) => {
+                    let AoCDate(year, day) = date;
+                    eprintln!(
+                        "{}",
+                        format!("day {year}-{day:02} panicked ({message}), it is left out")
+                            .yellow()
+                    );
+                    return None;
+                }
+            };
+
+            let accepted = answers
+                .get(&date)
+                .map(|file| parse_answers(file))
+                .unwrap_or_default();
+            let stars = stars::count(stars::stars(accepted, Some([result.part1, result.part2])));
+
+            Some(DayReport {
+                date,
+                result,
+                stars,
+            })
+        })
+        .collect())
+}
+
+pub fn report(markdown: bool, html: Option<PathBuf>) -> anyhow::Result<()> {
+    if cfg!(debug_assertions) {
+        println!(
+            "{}",
+            "this is a debug build, run with --release for numbers worth publishing".yellow()
+        );
+    }
+
+    let days = run_days()?;
+
+    if markdown {
+        markdown::write(&days, Path::new("./README.md"), Path::new("./badges"))?;
+    }
+
+    if let Some(dir) = html {
+        html::write(&days, &dir)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use std::{fs, time::Duration};
+
+use anyhow::Context;
+use owo_colors::OwoColorize as _;
+
+use advent_of_code::{AoCDate, get_solutions};
+
+use crate::affinity::Placement;
+
+/// 1, 2, 4 … up to and including `max`
+pub fn thread_counts(max: usize) -> Vec<usize> {
+    let mut counts = std::iter::successors(Some(1), |threads| Some(threads * 2))
+        .take_while(|threads| *threads < max)
+        .collect::<Vec<_>>();
+    counts.push(max);
+    counts
+}
+
+pub fn scaling(threads: Option<u16>, runs: u32, placement: &Placement) -> anyhow::Result<()> {
+    let max_threads = match threads {
+        Some(threads) => threads.into(),
+        None => std::thread::available_parallelism()
+            .context("could not determine the number of cores, pass --threads")?
+            .get(),
+    };
+
+    let mut solutions = get_solutions().into_iter().collect::<Vec<_>>();
+    solutions.sort_unstable_by_key(|(date, _)| *date);
+
+    for (AoCDate(year, day), solution) in solutions {
+        if !solution.info.tags.contains(&"parallel") {
+            continue;
+        }
+
+        let Ok(input) = fs::read_to_string(format!("./input/year{year}/day{day:02}.txt")) else {
+            eprintln!("skipping {year}-{day:02}: no input");
+            continue;
+        };
+
+        println!(
+            "{}",
+            format!("=== Day {year}-{day} scaling ===").magenta().bold()
+        );
+
+        let mut baseline = None;
+
+        for threads in thread_counts(max_threads) {
+            let pool = placement
+                .pool_builder()
+                .num_threads(threads)
+                .build()
+                .context("failed to build the thread pool")?;
+
+            // the fastest run is the one least disturbed by everything else on the machine
+            let result = (0..runs)
+                .map(|_| pool.install(|| (solution.run_fn)(input.clone())))
+                .min_by_key(|result| result.total_time())
+                .expect("at least one run");
+            let total = result.total_time();
+
+            let baseline: Duration = *baseline.get_or_insert(total);
+            let speedup = baseline.as_secs_f64() / total.as_secs_f64();
+            let efficiency = speedup / threads as f64;
+
+            let phases = result
+                .phases
+                .iter()
+                .map(|phase| format!("{} {:#?}", phase.phase.label().to_lowercase(), phase.time))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            println!(
+                "    {}: {} ({phases}) {} {}",
+                format!("{threads:>3} thread{}", if threads == 1 { "" } else { "s" }).italic(),
+                format!("{total:#?}").bold(),
+                format!("{speedup:.2}x").bold(),
+                format!("{:.0}% efficient", efficiency * 100.0).dimmed()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn powers_of_two_up_to_max() {
+        assert_eq!(thread_counts(1), [1]);
+        assert_eq!(thread_counts(6), [1, 2, 4, 6]);
+        assert_eq!(thread_counts(8), [1, 2, 4, 8]);
+    }
+}
@@ -0,0 +1,136 @@
+//! a small local http api over the solution registry:
+//!
+//! - `GET /solutions` lists every registered day
+//! - `POST /yearYYYY/dayDD` with the raw puzzle input as body solves both parts and reports
+//!   the time of every phase
+
+use std::{
+    collections::HashMap,
+    net::{SocketAddr, ToSocketAddrs},
+    panic::{AssertUnwindSafe, catch_unwind},
+};
+
+use anyhow::Context;
+use serde_json::{Value, json};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::{AoCDate, Solution, get_solutions};
+
+pub struct SolutionServer {
+    server: Server,
+    solutions: HashMap<AoCDate, Solution>,
+}
+
+fn parse_route(url: &str) -> Option<AoCDate> {
+    let (year, day) = url.strip_prefix('/')?.split_once('/')?;
+
+    Some(AoCDate(
+        year.strip_prefix("year")?.parse().ok()?,
+        day.strip_prefix("day")?.parse().ok()?,
+    ))
+}
+
+fn json_response(status: u16, body: Value) -> Response<std::io::Cursor<Vec<u8>>> {
+    let content_type =
+        Header::from_bytes("Content-Type", "application/json").expect("header is valid");
+
+    Response::from_data(body.to_string())
+        .with_status_code(status)
+        .with_header(content_type)
+}
+
+fn error(status: u16, message: &str) -> Response<std::io::Cursor<Vec<u8>>> {
+    json_response(status, json!({ "error": message }))
+}
+
+impl SolutionServer {
+    /// binds the server, use port 0 to let the os pick a free one
+    pub fn bind(addr: impl ToSocketAddrs) -> anyhow::Result<Self> {
+        let server = Server::http(addr)
+            .map_err(|error| anyhow::anyhow!(error))
+            .context("failed to bind http server")?;
+
+        Ok(Self {
+            server,
+            solutions: get_solutions(),
+        })
+    }
+
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.server.server_addr().to_ip()
+    }
+
+    /// handles requests one after the other so timings don't interfere
+    pub fn run(&self) -> anyhow::Result<()> {
+        for request in self.server.incoming_requests() {
+            // a single broken request must not take the server down
+            if let Err(error) = self.handle(request) {
+                eprintln!("failed to respond to request: {error}");
+            }
+        }
+
+        Ok(())
+    }
+
+    fn handle(&self, mut request: Request) -> std::io::Result<()> {
+        let url = request.url().to_owned();
+
+        let response = match (request.method(), url.as_str()) {
+            (Method::Get, "/solutions") => {
+                let mut dates = self.solutions.keys().copied().collect::<Vec<_>>();
+                dates.sort_unstable();
+
+                let solutions = dates
+                    .into_iter()
+                    .map(|AoCDate(year, day)| json!({ "year": year, "day": day }))
+                    .collect::<Vec<_>>();
+
+                json_response(200, Value::Array(solutions))
+            }
+            (method, url) => match parse_route(url) {
+                None => error(404, "not found"),
+                Some(_) if *method != Method::Post => {
+                    error(405, "post the puzzle input to this url")
+                }
+                Some(date) => match self.solutions.get(&date) {
+                    None => error(404, "no solution registered for day"),
+                    Some(solution) => {
+                        let mut input = String::new();
+                        match request.as_reader().read_to_string(&mut input) {
+                            Ok(_) => self.solve(date, solution, input),
+                            Err(_) => error(400, "input is not valid utf-8"),
+                        }
+                    }
+                },
+            },
+        };
+
+        request.respond(response)
+    }
+
+    fn solve(
+        &self,
+        AoCDate(year, day): AoCDate,
+        solution: &Solution,
+        input: String,
+    ) -> Response<std::io::Cursor<Vec<u8>>> {
+        // solutions expect well formed input and panic otherwise
+        let Ok(result) = catch_unwind(AssertUnwindSafe(|| (solution.run_fn)(input))) else {
+            return error(422, "solution panicked, is the input valid?");
+        };
+
+        let mut body = json!({
+            "year": year,
+            "day": day,
+            "part1": { "answer": result.part1 },
+            "part2": { "answer": result.part2 },
+        });
+
+        // days that solve both parts at once report a single `both_parts` time
+        for phase in result.phases {
+            body[phase.phase.name()]["time_ns"] = (phase.time.as_nanos() as u64).into();
+        }
+
+        json_response(200, body)
+    }
+}
@@ -0,0 +1,25 @@
+//! a star counts once the runner reproduces the accepted answer in `answers/`, so `calendar` and
+//! `report` agree and a part that broke since loses its star
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Star {
+    Earned,
+    /// an accepted answer the runner does not reproduce: the day is unregistered, has no input,
+    /// panics or gets a different answer, e.g. from an unfinished part
+    Unbacked,
+    Missing,
+}
+
+/// `answers` is what the runner got for both parts, `None` if it could not run the day
+pub fn stars(accepted: [Option<u64>; 2], answers: Option<[u64; 2]>) -> [Star; 2] {
+    [0, 1].map(|part| match (accepted[part], answers) {
+        (None, _) => Star::Missing,
+        (Some(accepted), Some(answers)) if answers[part] == accepted => Star::Earned,
+        (Some(_), _) => Star::Unbacked,
+    })
+}
+
+/// how many of the stars are earned
+pub fn count(stars: [Star; 2]) -> u8 {
+    stars.iter().filter(|&&star| star == Star::Earned).count() as u8
+}
@@ -0,0 +1,177 @@
+//! `--timings` splits every phase into three figures, so it is clear which one a number quotes:
+//!
+//! - cold: reading the input after evicting it from the page cache, plus the first parse (or
+//!   the first call of `solve` for days that solve both parts at once)
+//! - first call: the first call of each phase in this process
+//! - steady state: the mean of repeated calls after a warmup
+
+use std::{
+    fs::File,
+    io::Read as _,
+    os::fd::AsRawFd as _,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use owo_colors::OwoColorize as _;
+
+use advent_of_code::{AoCDate, Phase, PhaseRunner, Solution};
+
+const WARMUP: Duration = Duration::from_millis(100);
+const MEASUREMENT: Duration = Duration::from_millis(300);
+const MIN_ITERATIONS: u32 = 3;
+
+#[derive(Clone, Copy, Default)]
+pub struct PhaseTimings {
+    pub first_call: Duration,
+    pub steady_state: Duration,
+}
+
+/// times the first call of every phase, then warms it up and takes the mean of repeated calls
+#[derive(Default)]
+pub struct TimingsRunner {
+    pub phases: Vec<(Phase, PhaseTimings)>,
+}
+
+impl PhaseRunner for TimingsRunner {
+    fn run_phase(&mut self, phase: Phase, routine: &mut dyn FnMut()) {
+        let start = Instant::now();
+        routine();
+        let first_call = start.elapsed();
+
+        let warmup = Instant::now();
+        while warmup.elapsed() < WARMUP {
+            routine();
+        }
+
+        let mut iterations = 0;
+        let start = Instant::now();
+        while iterations < MIN_ITERATIONS || start.elapsed() < MEASUREMENT {
+            routine();
+            iterations += 1;
+        }
+
+        self.phases.push((
+            phase,
+            PhaseTimings {
+                first_call,
+                steady_state: start.elapsed() / iterations,
+            },
+        ));
+    }
+}
+
+/// whether any page of the file is in the page cache. mapping it does not read anything, and
+/// `mincore` only reports which pages are resident
+fn cached_pages(file: &File) -> anyhow::Result<bool> {
+    let length = file.metadata().context("failed to stat input")?.len() as usize;
+    if length == 0 {
+        return Ok(false);
+    }
+
+    let address = unsafe {
+        libc::mmap(
+            std::ptr::null_mut(),
+            length,
+            libc::PROT_READ,
+            libc::MAP_SHARED,
+            file.as_raw_fd(),
+            0,
+        )
+    };
+    if address == libc::MAP_FAILED {
+        return Err(std::io::Error::last_os_error()).context("failed to map input");
+    }
+
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+    let mut resident = vec![0u8; length.div_ceil(page_size)];
+    let result = unsafe { libc::mincore(address, length, resident.as_mut_ptr()) };
+    let error = std::io::Error::last_os_error();
+    unsafe { libc::munmap(address, length) };
+
+    if result != 0 {
+        return Err(error).context("mincore failed");
+    }
+
+    Ok(resident.iter().any(|page| page & 1 != 0))
+}
+
+/// reads the file after asking the kernel to evict it from the page cache. the advice only
+/// applies to clean pages and may be ignored, so returns whether the file actually left the cache
+fn read_cold(path: &Path) -> anyhow::Result<(String, Duration, bool)> {
+    let file = File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+    let advised =
+        unsafe { libc::posix_fadvise(file.as_raw_fd(), 0, 0, libc::POSIX_FADV_DONTNEED) } == 0;
+    let evicted = advised && !cached_pages(&file)?;
+    drop(file);
+
+    let start = Instant::now();
+    let mut input = String::new();
+    File::open(path)
+        .and_then(|mut file| file.read_to_string(&mut input))
+        .with_context(|| format!("failed to read {}", path.display()))?;
+
+    Ok((input, start.elapsed(), evicted))
+}
+
+pub fn timings(mut days: Vec<(AoCDate, &Solution)>) -> anyhow::Result<()> {
+    days.sort_unstable_by_key(|(date, _)| *date);
+
+    let mut totals = [Duration::ZERO; 3];
+
+    for (AoCDate(year, day), solution) in days {
+        let path = format!("./input/year{year}/day{day:02}.txt");
+        let (input, io_time, evicted) = read_cold(Path::new(&path))?;
+
+        let mut runner = TimingsRunner::default();
+        (solution.phases_fn)(&input, &mut runner);
+
+        println!("{}", format!("=== Day {year}-{day} ===").magenta().bold());
+        println!(
+            "    {:<10} {:>20} {:>14} {:>14}",
+            "",
+            "cold (I/O + parse)".italic(),
+            "first call".italic(),
+            "steady state".italic()
+        );
+
+        for (phase, timings) in runner.phases {
+            // the first phase is the one that sees the input first
+            let cold = match phase {
+                Phase::Parse | Phase::BothParts => {
+                    let cold = io_time + timings.first_call;
+                    totals[0] += cold;
+                    format!("{cold:#?}")
+                }
+                Phase::Part1 | Phase::Part2 => String::new(),
+            };
+            totals[1] += timings.first_call;
+            totals[2] += timings.steady_state;
+
+            println!(
+                "    {:<10} {:>20} {:>14} {:>14}",
+                phase.label().italic(),
+                cold.bold(),
+                format!("{:#?}", timings.first_call).bold(),
+                format!("{:#?}", timings.steady_state).bold()
+            );
+        }
+
+        if !evicted {
+            println!(
+                "{}",
+                "    the input stayed in the page cache, cold includes a cached read".dimmed()
+            );
+        }
+    }
+
+    println!(
+        "Cold {}, first call {}, steady state {}",
+        format!("{:#?}", totals[0]).bold(),
+        format!("{:#?}", totals[1]).bold(),
+        format!("{:#?}", totals[2]).bold()
+    );
+
+    Ok(())
+}
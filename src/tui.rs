@@ -0,0 +1,517 @@
+//! a full screen dashboard that runs the days one after another on a worker thread and shows
+//! every result as soon as it is done, instead of printing everything at the end
+
+use std::{
+    collections::HashMap,
+    path::Path,
+    process::Command,
+    sync::mpsc::{self, Receiver, Sender},
+    thread,
+    time::Duration,
+};
+
+use anyhow::Context;
+use ratatui::{
+    DefaultTerminal, Frame,
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style, Stylize as _},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Row, Table, TableState},
+};
+
+use advent_of_code::{AoCDate, Phase, Solution, get_solutions, load_all_input_files};
+
+use crate::{affinity::Placement, catch_panic};
+
+const BAR_WIDTH: usize = 30;
+
+/// `a` stops re-running a day after it failed this often in a row, `r` still does
+const MAX_FAILURES: u32 = 3;
+
+/// colors of the phases in the order of [`Phase::ALL`]
+const COLORS: [Color; Phase::ALL.len()] =
+    [Color::Blue, Color::Green, Color::Yellow, Color::Magenta];
+
+enum Status {
+    NoInput,
+    /// has an input but was not queued yet
+    Idle,
+    Queued,
+    Running,
+    Done(Outcome),
+    Failed(String),
+}
+
+/// answers and timings of a run, parts that were toggled off have no answer
+struct Outcome {
+    phases: Vec<(Phase, Duration)>,
+    answers: [Option<u64>; 2],
+}
+
+impl Outcome {
+    fn total(&self) -> Duration {
+        self.phases.iter().map(|(_, time)| *time).sum()
+    }
+}
+
+struct Job {
+    index: usize,
+    date: AoCDate,
+    parts: [bool; 2],
+}
+
+enum Message {
+    Started(usize),
+    Finished(usize, Result<Outcome, String>),
+}
+
+fn run_day(solution: &Solution, input: &str, parts: [bool; 2]) -> Outcome {
+    match parts {
+        [true, true] => {
+            let result = (solution.run_fn)(input.to_owned());
+
+            Outcome {
+                phases: result
+                    .phases
+                    .iter()
+                    .map(|phase| (phase.phase, phase.time))
+                    .collect(),
+                answers: [Some(result.part1), Some(result.part2)],
+            }
+        }
+        _ => {
+            let part = if parts[0] { 1 } else { 2 };
+            let result = (solution.part_fn)(input, part);
+
+            let mut answers = [None; 2];
+            answers[usize::from(part) - 1] = Some(result.answer);
+
+            Outcome {
+                phases: result.phases,
+                answers,
+            }
+        }
+    }
+}
+
+/// runs the jobs one at a time so the timings do not disturb each other
+fn worker(
+    jobs: Receiver<Job>,
+    messages: Sender<Message>,
+    inputs: HashMap<AoCDate, String>,
+    pool: rayon::ThreadPool,
+) {
+    let solutions = get_solutions();
+
+    for Job { index, date, parts } in jobs {
+        if messages.send(Message::Started(index)).is_err() {
+            return;
+        }
+
+        // the default hook would print over the dashboard, the message goes into the row instead
+        let outcome =
+            catch_panic(|| pool.install(|| run_day(&solutions[&date], &inputs[&date], parts)));
+
+        if messages.send(Message::Finished(index, outcome)).is_err() {
+            return;
+        }
+    }
+}
+
+struct DayRow {
+    date: AoCDate,
+    title: &'static str,
+    status: Status,
+    /// failed runs since the last successful one
+    failures: u32,
+}
+
+struct App {
+    days: Vec<DayRow>,
+    table: TableState,
+    parts: [bool; 2],
+    jobs: Sender<Job>,
+}
+
+impl App {
+    fn queue(&mut self, index: usize) {
+        let row = &mut self.days[index];
+        // a day is queued at most once, holding a key must not pile up runs
+        if matches!(
+            row.status,
+            Status::NoInput | Status::Queued | Status::Running
+        ) {
+            return;
+        }
+
+        row.status = Status::Queued;
+        // the worker only stops once the jobs sender is dropped
+        let _ = self.jobs.send(Job {
+            index,
+            date: row.date,
+            parts: self.parts,
+        });
+    }
+
+    fn queue_all(&mut self) {
+        for index in 0..self.days.len() {
+            if self.days[index].failures < MAX_FAILURES {
+                self.queue(index);
+            }
+        }
+    }
+
+    /// at least one part stays enabled
+    fn toggle_part(&mut self, part: usize) {
+        let mut parts = self.parts;
+        parts[part] = !parts[part];
+
+        if parts.contains(&true) {
+            self.parts = parts;
+        }
+    }
+
+    fn slowest(&self) -> Duration {
+        self.days
+            .iter()
+            .filter_map(|row| match &row.status {
+                Status::Done(outcome) => Some(outcome.total()),
+                _ => None,
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn timing_bar(outcome: &Outcome, slowest: Duration) -> Line<'static> {
+        let scale = BAR_WIDTH as f64 / slowest.as_secs_f64().max(f64::EPSILON);
+
+        Line::from(
+            outcome
+                .phases
+                .iter()
+                .map(|(phase, time)| {
+                    let width = (time.as_secs_f64() * scale).round() as usize;
+                    Span::styled("█".repeat(width), Style::new().fg(COLORS[*phase as usize]))
+                })
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [table_area, footer_area] =
+            Layout::vertical([Constraint::Min(3), Constraint::Length(3)]).areas(frame.area());
+
+        let slowest = self.slowest();
+        let answer = |answer: Option<u64>| {
+            answer.map_or_else(|| "-".to_owned(), |answer| answer.to_string())
+        };
+
+        let rows = self.days.iter().map(|row| {
+            let AoCDate(year, day) = row.date;
+            let date = format!("{year}-{day:02}");
+
+            let (status, answers, bar, time) = match &row.status {
+                Status::NoInput => (
+                    "no input".dark_gray(),
+                    [String::new(), String::new()],
+                    Line::default(),
+                    String::new(),
+                ),
+                Status::Idle => (
+                    "idle".dark_gray(),
+                    [String::new(), String::new()],
+                    Line::default(),
+                    String::new(),
+                ),
+                Status::Queued => (
+                    "queued".gray(),
+                    [String::new(), String::new()],
+                    Line::default(),
+                    String::new(),
+                ),
+                Status::Running => (
+                    "running".yellow(),
+                    [String::new(), String::new()],
+                    Line::default(),
+                    String::new(),
+                ),
+                Status::Done(outcome) => (
+                    "done".green(),
+                    outcome.answers.map(answer),
+                    Self::timing_bar(outcome, slowest),
+                    format!("{:#?}", outcome.total()),
+                ),
+                Status::Failed(message) if row.failures >= MAX_FAILURES => (
+                    format!("failed {}×", row.failures).red().bold(),
+                    [message.clone(), String::new()],
+                    Line::default(),
+                    String::new(),
+                ),
+                Status::Failed(message) => (
+                    "failed".red(),
+                    [message.clone(), String::new()],
+                    Line::default(),
+                    String::new(),
+                ),
+            };
+            let [part1, part2] = answers;
+
+            Row::new(vec![
+                date.into(),
+                row.title.into(),
+                status.into(),
+                part1.into(),
+                part2.into(),
+                bar,
+                time.into(),
+            ])
+        });
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(7),
+                Constraint::Length(24),
+                Constraint::Length(10),
+                Constraint::Length(16),
+                Constraint::Length(16),
+                Constraint::Length(BAR_WIDTH as u16),
+                Constraint::Length(12),
+            ],
+        )
+        .header(
+            Row::new([
+                "Day", "Title", "Status", "Part 1", "Part 2", "Phases", "Time",
+            ])
+            .style(Style::new().add_modifier(Modifier::BOLD | Modifier::ITALIC)),
+        )
+        .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+        .block(Block::bordered().title(" advent of code ".magenta().bold()));
+
+        frame.render_stateful_widget(table, table_area, &mut self.table);
+
+        let done = self
+            .days
+            .iter()
+            .filter_map(|row| match &row.status {
+                Status::Done(outcome) => Some(outcome.total()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut legend = Phase::ALL
+            .into_iter()
+            .flat_map(|phase| {
+                [
+                    Span::styled("█ ", Style::new().fg(COLORS[phase as usize])),
+                    Span::raw(format!("{}  ", phase.label())),
+                ]
+            })
+            .collect::<Vec<_>>();
+        legend.push(
+            Span::raw(format!(
+                "total {:#?} for {} days",
+                done.iter().sum::<Duration>(),
+                done.len()
+            ))
+            .bold(),
+        );
+
+        let part = |enabled: bool, part: u8| {
+            if enabled {
+                format!("[{part}]").green()
+            } else {
+                format!(" {part} ").dark_gray()
+            }
+        };
+        let keys = Line::from(vec![
+            "↑↓".bold(),
+            " select  ".into(),
+            "r".bold(),
+            " re-run  ".into(),
+            "a".bold(),
+            " re-run all  ".into(),
+            "1 2".bold(),
+            " toggle parts ".into(),
+            part(self.parts[0], 1),
+            part(self.parts[1], 2),
+            "  ".into(),
+            "p".bold(),
+            " open input  ".into(),
+            "q".bold(),
+            " quit".into(),
+        ]);
+
+        frame.render_widget(
+            Paragraph::new(vec![Line::from(legend), keys]).block(Block::new()),
+            footer_area,
+        );
+    }
+}
+
+/// hands the terminal to the pager and takes it back afterwards
+fn open_in_pager(terminal: &mut DefaultTerminal, path: &Path) -> anyhow::Result<()> {
+    let pager = std::env::var("PAGER").unwrap_or_else(|_| "less".to_owned());
+
+    ratatui::restore();
+    let status = Command::new(&pager).arg(path).status();
+    *terminal = ratatui::try_init().context("failed to set up the terminal")?;
+    terminal.clear()?;
+
+    status.with_context(|| format!("failed to run {pager}"))?;
+
+    Ok(())
+}
+
+fn event_loop(
+    terminal: &mut DefaultTerminal,
+    app: &mut App,
+    messages: &Receiver<Message>,
+) -> anyhow::Result<()> {
+    loop {
+        for message in messages.try_iter() {
+            match message {
+                Message::Started(index) => app.days[index].status = Status::Running,
+                Message::Finished(index, Ok(outcome)) => {
+                    app.days[index].status = Status::Done(outcome);
+                    app.days[index].failures = 0;
+                }
+                Message::Finished(index, Err(message)) => {
+                    app.days[index].status = Status::Failed(message);
+                    app.days[index].failures += 1;
+                }
+            }
+        }
+
+        terminal.draw(|frame| app.draw(frame))?;
+
+        if !event::poll(Duration::from_millis(50))? {
+            continue;
+        }
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Up | KeyCode::Char('k') => app.table.select_previous(),
+            KeyCode::Down | KeyCode::Char('j') => app.table.select_next(),
+            KeyCode::Char('r') | KeyCode::Enter => {
+                if let Some(index) = app.table.selected() {
+                    app.queue(index);
+                }
+            }
+            KeyCode::Char('a') => app.queue_all(),
+            KeyCode::Char('1') => app.toggle_part(0),
+            KeyCode::Char('2') => app.toggle_part(1),
+            KeyCode::Char('p') => {
+                if let Some(row) = app.table.selected().map(|index| &app.days[index]) {
+                    let AoCDate(year, day) = row.date;
+                    open_in_pager(
+                        terminal,
+                        Path::new(&format!("./input/year{year}/day{day:02}.txt")),
+                    )?;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+pub fn tui(threads: Option<u16>, placement: &Placement) -> anyhow::Result<()> {
+    let inputs = load_all_input_files(Path::new("./input"))?;
+    let mut solutions = get_solutions().into_iter().collect::<Vec<_>>();
+    solutions.sort_unstable_by_key(|(date, _)| *date);
+
+    let days = solutions
+        .into_iter()
+        .map(|(date, solution)| DayRow {
+            date,
+            title: solution.info.title,
+            status: if inputs.contains_key(&date) {
+                Status::Idle
+            } else {
+                Status::NoInput
+            },
+            failures: 0,
+        })
+        .collect();
+
+    // the ui thread is not part of the pool, so it stays responsive while a day runs
+    let pool = placement
+        .pool_builder()
+        .num_threads(threads.map_or(0, usize::from))
+        .build()
+        .context("failed to build the thread pool")?;
+
+    let (jobs, job_receiver) = mpsc::channel();
+    let (message_sender, messages) = mpsc::channel();
+    let worker = thread::spawn(move || worker(job_receiver, message_sender, inputs, pool));
+
+    let mut app = App {
+        days,
+        table: TableState::default().with_selected(Some(0)),
+        parts: [true, true],
+        jobs,
+    };
+    app.queue_all();
+
+    let mut terminal = ratatui::try_init().context("failed to set up the terminal")?;
+    let result = event_loop(&mut terminal, &mut app, &messages);
+    ratatui::restore();
+
+    // the worker finishes the day it is running and stops once the queue is gone
+    drop(app);
+    drop(messages);
+    let _ = worker.join();
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app(statuses: Vec<Status>) -> (App, Receiver<Job>) {
+        let (jobs, job_receiver) = mpsc::channel();
+        let days = statuses
+            .into_iter()
+            .enumerate()
+            .map(|(index, status)| DayRow {
+                date: AoCDate(2024, index as u8 + 1),
+                title: "",
+                status,
+                failures: 0,
+            })
+            .collect();
+
+        let app = App {
+            days,
+            table: TableState::default(),
+            parts: [true, true],
+            jobs,
+        };
+
+        (app, job_receiver)
+    }
+
+    #[test]
+    fn queue_all_sends_a_job_per_day_with_an_input() {
+        let (mut app, jobs) = app(vec![Status::Idle, Status::NoInput, Status::Idle]);
+
+        app.queue_all();
+
+        let dates = jobs.try_iter().map(|job| job.date).collect::<Vec<_>>();
+        assert_eq!(dates, [AoCDate(2024, 1), AoCDate(2024, 3)]);
+        assert!(matches!(app.days[0].status, Status::Queued));
+
+        // queued days are not sent twice
+        app.queue_all();
+        assert_eq!(jobs.try_iter().count(), 0);
+    }
+}
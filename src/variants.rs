@@ -0,0 +1,87 @@
+use std::{collections::HashMap, path::Path};
+
+use owo_colors::{OwoColorize as _, Style};
+
+use advent_of_code::{AoCDate, get_solutions, load_all_input_files};
+
+use crate::timings::TimingsRunner;
+
+/// runs every part that has variants next to them on all inputs and fails if any disagree. the
+/// times are steady state means like `--timings` reports them, a single call is mostly noise
+pub fn compare(input_path: &Path) -> anyhow::Result<()> {
+    let input_files: HashMap<AoCDate, String> = load_all_input_files(input_path)?;
+
+    let mut solutions = get_solutions().into_iter().collect::<Vec<_>>();
+    solutions.sort_unstable_by_key(|(date, _)| *date);
+
+    let mut compared_days = 0;
+    let mut disagreements = 0;
+
+    for (AoCDate(year, day), solution) in solutions {
+        let Some(input) = input_files.get(&AoCDate(year, day)) else {
+            continue;
+        };
+
+        let mut runner = TimingsRunner::default();
+        let results = (solution.variants_fn)(input, &mut runner);
+        if results.is_empty() {
+            continue;
+        }
+        let results = results
+            .into_iter()
+            .zip(runner.phases)
+            .map(|(result, (_, timings))| (result, timings.steady_state))
+            .collect::<Vec<_>>();
+        compared_days += 1;
+
+        println!(
+            "{}",
+            format!("=== Day {year}-{day} variants ===")
+                .magenta()
+                .bold()
+        );
+
+        for part in [1, 2] {
+            let part_results = results
+                .iter()
+                .filter(|(result, _)| result.part == part)
+                .collect::<Vec<_>>();
+            let Some((reference, reference_time)) = part_results.first().copied() else {
+                continue;
+            };
+
+            println!("    {}", format!("Part {part}").italic());
+
+            for (result, time) in part_results {
+                let speedup = reference_time.as_secs_f64() / time.as_secs_f64();
+                let agrees = result.answer == reference.answer;
+
+                let (style, verdict) = if agrees {
+                    (Style::new().bold(), String::new())
+                } else {
+                    disagreements += 1;
+                    (Style::new().bold().red(), " disagrees".red().to_string())
+                };
+
+                println!(
+                    "        {}: {} (in {:#?}, {speedup:.2}x){}",
+                    result.label.italic(),
+                    result.answer.style(style),
+                    time,
+                    verdict
+                );
+            }
+        }
+    }
+
+    println!(
+        "{}",
+        format!("Compared variants on {compared_days} days").bold()
+    );
+
+    if disagreements > 0 {
+        anyhow::bail!("{disagreements} variants disagree with the registered part");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+//! `verify --determinism` runs every day on differently sized thread pools and with the input at
+//! different addresses, and reports days whose answers depend on either
+
+use std::{collections::BTreeMap, path::Path};
+
+use anyhow::Context;
+use owo_colors::OwoColorize as _;
+
+use advent_of_code::{AoCDate, get_solutions, load_all_input_files};
+
+use crate::{affinity::Placement, catch_panic, scaling::thread_counts};
+
+/// where the input starts relative to its allocation, to move it off the allocator's alignment
+const OFFSETS: [usize; 4] = [0, 1, 7, 33];
+
+/// a copy of `input` that starts `offset` bytes into its buffer
+fn shifted(input: &str, offset: usize) -> Vec<u8> {
+    let mut buffer = Vec::with_capacity(offset + input.len());
+    buffer.resize(offset, b' ');
+    buffer.extend_from_slice(input.as_bytes());
+    buffer
+}
+
+pub fn determinism(threads: Option<u16>, runs: u32, placement: &Placement) -> anyhow::Result<()> {
+    // oversubscribing is fine here, only the answers matter, so small machines still get to
+    // try a few thread counts by default
+    let max_threads = match threads {
+        Some(threads) => threads.into(),
+        None => std::thread::available_parallelism()
+            .context("could not determine the number of cores, pass --threads")?
+            .get()
+            .max(4),
+    };
+
+    let pools = thread_counts(max_threads)
+        .into_iter()
+        .map(|threads| {
+            placement
+                .pool_builder()
+                .num_threads(threads)
+                .build()
+                .map(|pool| (threads, pool))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .context("failed to build the thread pools")?;
+
+    let inputs = load_all_input_files(Path::new("./input"))?;
+    let mut solutions = get_solutions().into_iter().collect::<Vec<_>>();
+    solutions.sort_unstable_by_key(|(date, _)| *date);
+
+    let mut varying_days = 0;
+    let mut checked_days = 0;
+
+    for (AoCDate(year, day), solution) in solutions {
+        let Some(input) = inputs.get(&AoCDate(year, day)) else {
+            continue;
+        };
+        checked_days += 1;
+
+        // answer, or `None` for a panic -> the runs that produced it
+        let mut answers: [BTreeMap<Option<u64>, Vec<String>>; 2] = Default::default();
+        // every combination panics the same way more often than not, only show it once
+        let mut panics: [Option<String>; 2] = Default::default();
+        let mut run_count = 0;
+
+        for run in 1..=runs {
+            for (threads, pool) in &pools {
+                for offset in OFFSETS {
+                    let buffer = shifted(input, offset);
+                    let input = std::str::from_utf8(&buffer[offset..]).expect("copied from a str");
+                    let alignment = input.as_ptr() as usize % 64;
+
+                    for part in [1, 2] {
+                        let answer =
+                            catch_panic(|| pool.install(|| (solution.part_fn)(input, part).answer))
+                                .map_err(|message| {
+                                    panics[usize::from(part) - 1].get_or_insert(message);
+                                })
+                                .ok();
+
+                        answers[usize::from(part) - 1]
+                            .entry(answer)
+                            .or_default()
+                            .push(format!(
+                                "run {run}, {threads} thread{}, address {alignment} mod 64",
+                                if *threads == 1 { "" } else { "s" }
+                            ));
+                    }
+                    run_count += 1;
+                }
+            }
+        }
+
+        println!(
+            "{}",
+            format!("=== Day {year}-{day} determinism ===")
+                .magenta()
+                .bold()
+        );
+
+        let mut varies = false;
+        for ((part, answers), panic) in [1, 2].into_iter().zip(answers).zip(panics) {
+            let describe = |answer: Option<u64>| match (answer, &panic) {
+                (Some(answer), _) => answer.to_string(),
+                (None, Some(message)) => format!("panicked ({message})"),
+                (None, None) => "panicked".to_owned(),
+            };
+
+            if answers.len() == 1 {
+                let answer = answers.into_keys().next().flatten();
+                println!(
+                    "    {}: {} in all {run_count} runs",
+                    format!("Part {part}").italic(),
+                    describe(answer).bold()
+                );
+                continue;
+            }
+
+            varies = true;
+            println!(
+                "    {}: {}",
+                format!("Part {part}").italic(),
+                format!("{} different answers", answers.len()).red().bold()
+            );
+            for (answer, configurations) in answers {
+                println!(
+                    "        {} in {} runs, e.g. {}",
+                    describe(answer).bold(),
+                    configurations.len(),
+                    configurations[0].dimmed()
+                );
+            }
+        }
+
+        varying_days += usize::from(varies);
+    }
+
+    println!(
+        "{}",
+        format!(
+            "Checked {checked_days} days on {} thread counts and {} input offsets",
+            pools.len(),
+            OFFSETS.len()
+        )
+        .bold()
+    );
+
+    if varying_days > 0 {
+        anyhow::bail!("{varying_days} days give different answers between runs");
+    }
+
+    Ok(())
+}
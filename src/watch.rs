@@ -0,0 +1,353 @@
+use std::{
+    collections::HashMap,
+    ffi::CString,
+    fs::{self, File},
+    io::Read as _,
+    os::fd::{AsRawFd as _, FromRawFd as _},
+    path::{Path, PathBuf},
+    process::{Command, Output},
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, bail};
+use owo_colors::OwoColorize as _;
+
+use advent_of_code::{AoCDate, plugin::Plugin};
+
+use crate::print_result;
+
+/// editors tend to write a file in several steps (truncate, write, rename, chmod).
+/// wait until no event arrived for this long before rebuilding
+const DEBOUNCE: Duration = Duration::from_millis(150);
+
+const WATCH_MASK: u32 =
+    libc::IN_CLOSE_WRITE | libc::IN_MOVED_TO | libc::IN_CREATE | libc::IN_DELETE;
+
+struct Inotify {
+    file: File,
+    // watch descriptor -> file we care about in that directory
+    watches: HashMap<i32, PathBuf>,
+}
+
+impl Inotify {
+    fn new() -> anyhow::Result<Self> {
+        let fd = unsafe { libc::inotify_init1(libc::IN_CLOEXEC) };
+        if fd < 0 {
+            return Err(std::io::Error::last_os_error()).context("inotify_init1 failed");
+        }
+
+        Ok(Self {
+            file: unsafe { File::from_raw_fd(fd) },
+            watches: HashMap::new(),
+        })
+    }
+
+    /// watches the parent directory instead of the file itself, since editors often replace
+    /// files by renaming a temporary file over them, which would drop a watch on the file
+    fn watch_file(&mut self, path: &Path) -> anyhow::Result<bool> {
+        let Some(directory) = path.parent() else {
+            bail!("cannot watch {}", path.display());
+        };
+
+        if !directory.is_dir() {
+            return Ok(false);
+        }
+
+        let c_directory = CString::new(directory.as_os_str().as_encoded_bytes())
+            .context("path contains a nul byte")?;
+
+        let wd = unsafe {
+            libc::inotify_add_watch(self.file.as_raw_fd(), c_directory.as_ptr(), WATCH_MASK)
+        };
+        if wd < 0 {
+            return Err(std::io::Error::last_os_error())
+                .with_context(|| format!("failed to watch {}", directory.display()));
+        }
+
+        self.watches.insert(wd, path.to_owned());
+
+        Ok(true)
+    }
+
+    fn poll(&self, timeout: Option<Duration>) -> anyhow::Result<bool> {
+        let mut poll_fd = libc::pollfd {
+            fd: self.file.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+
+        let timeout = timeout.map_or(-1, |timeout| timeout.as_millis() as i32);
+
+        loop {
+            match unsafe { libc::poll(&mut poll_fd, 1, timeout) } {
+                -1 => {
+                    let error = std::io::Error::last_os_error();
+                    if error.kind() != std::io::ErrorKind::Interrupted {
+                        return Err(error).context("polling inotify failed");
+                    }
+                }
+                0 => return Ok(false),
+                _ => return Ok(true),
+            }
+        }
+    }
+
+    /// reads all pending events and collects the watched files that changed
+    fn read_changes(&mut self, changed: &mut Vec<PathBuf>) -> anyhow::Result<()> {
+        // inotify_event is 4 byte aligned and the kernel never splits an event across reads
+        let mut buffer = [0u32; 1024];
+        let bytes = unsafe {
+            std::slice::from_raw_parts_mut(buffer.as_mut_ptr().cast::<u8>(), size_of_val(&buffer))
+        };
+
+        let length = self
+            .file
+            .read(bytes)
+            .context("failed to read inotify events")?;
+
+        let mut offset = 0;
+        while offset < length {
+            let event = unsafe { &*bytes.as_ptr().add(offset).cast::<libc::inotify_event>() };
+            let name_start = offset + size_of::<libc::inotify_event>();
+            let name = &bytes[name_start..name_start + event.len as usize];
+            let name = name.split(|b| *b == 0).next().unwrap_or_default();
+
+            if let Some(watched) = self.watches.get(&event.wd)
+                && watched
+                    .file_name()
+                    .is_some_and(|file_name| file_name.as_encoded_bytes() == name)
+                && !changed.contains(watched)
+            {
+                changed.push(watched.clone());
+            }
+
+            offset = name_start + event.len as usize;
+        }
+
+        Ok(())
+    }
+
+    /// blocks until a watched file changes and no further changes arrive within the debounce window
+    fn wait_for_changes(&mut self) -> anyhow::Result<Vec<PathBuf>> {
+        let mut changed = Vec::new();
+
+        while changed.is_empty() {
+            self.poll(None)?;
+            self.read_changes(&mut changed)?;
+        }
+
+        while self.poll(Some(DEBOUNCE))? {
+            self.read_changes(&mut changed)?;
+        }
+
+        Ok(changed)
+    }
+}
+
+fn cargo(args: &[&str]) -> anyhow::Result<(Duration, Output)> {
+    let start = Instant::now();
+    let output = Command::new("cargo")
+        .args(args)
+        .env("CARGO_TERM_COLOR", "always")
+        // backtraces don't fit into the compact view
+        .env("RUST_BACKTRACE", "0")
+        .output()
+        .context("failed to run cargo")?;
+
+    Ok((start.elapsed(), output))
+}
+
+fn print_status(label: &str, success: bool, detail: &str) {
+    if success {
+        println!("{} {detail}", format!("{label:>6}").green().bold());
+    } else {
+        println!("{} {detail}", format!("{label:>6}").red().bold());
+    }
+}
+
+struct Session {
+    year: u16,
+    day: u8,
+    input_path: PathBuf,
+    /// false if the input's directory did not exist when watching started
+    input_watched: bool,
+    /// only used in plugin mode, the runner process stays alive and keeps the input around
+    plugin_input: Option<String>,
+    plugin: bool,
+    threads: Option<u16>,
+}
+
+impl Session {
+    fn run(&mut self, run_count: usize, changed: &[PathBuf]) -> anyhow::Result<()> {
+        let (year, day) = (self.year, self.day);
+        let module = format!("year{year}::day{day:02}::");
+
+        // clear the screen and move the cursor home
+        print!("\x1b[2J\x1b[H");
+        println!(
+            "{}",
+            format!("=== Watching Day {year}-{day} ===")
+                .magenta()
+                .bold()
+        );
+        println!(
+            "{}",
+            format!(
+                "    run #{run_count}, changed: {}",
+                changed
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+            .italic()
+        );
+        if !self.input_watched {
+            println!(
+                "{}",
+                format!(
+                    "    {} has no directory yet, restart watch to pick up changes to it",
+                    self.input_path.display()
+                )
+                .dimmed()
+            );
+        }
+
+        let build_args: &[&str] = if self.plugin {
+            &["build", "--release", "--quiet", "--example", "plugin"]
+        } else {
+            &["build", "--release", "--quiet"]
+        };
+
+        let (build_time, build) = cargo(build_args)?;
+        if !build.status.success() {
+            print_status("build", false, &format!("failed in {build_time:#?}"));
+            println!("{}", String::from_utf8_lossy(&build.stderr));
+            return Ok(());
+        }
+        print_status("build", true, &format!("in {build_time:#?}"));
+
+        let (test_time, test) = cargo(&[
+            "test",
+            "--release",
+            "--quiet",
+            // the day modules and their tests live in the library
+            "--lib",
+            &module,
+        ])?;
+        let test_stdout = String::from_utf8_lossy(&test.stdout);
+        let summary = test_stdout
+            .lines()
+            .find(|line| line.starts_with("test result"))
+            .unwrap_or("no test result")
+            .trim_start_matches("test result: ");
+        print_status(
+            "tests",
+            test.status.success(),
+            &format!("{summary} (in {test_time:#?})"),
+        );
+
+        if !test.status.success() {
+            // only show the failure details, not the list of passing tests
+            let failures = test_stdout
+                .split_once("failures:")
+                .map_or(test_stdout.as_ref(), |(_, failures)| failures);
+            for line in failures.lines().filter(|line| !line.is_empty()).take(20) {
+                println!("       {line}");
+            }
+        }
+
+        if self.plugin {
+            self.run_plugin(changed)
+        } else {
+            self.run_process()
+        }
+    }
+
+    fn run_process(&self) -> anyhow::Result<()> {
+        let (year, day) = (self.year.to_string(), self.day.to_string());
+        let mut args = vec!["run", "--release", "--quiet", "--", "day", &year, &day];
+
+        let threads = self.threads.map(|threads| threads.to_string());
+        if let Some(threads) = &threads {
+            args.extend(["--threads", threads]);
+        }
+
+        let (_, solution) = cargo(&args)?;
+
+        print_status("run", solution.status.success(), "");
+        print!("{}", String::from_utf8_lossy(&solution.stdout));
+        if !solution.status.success() {
+            print!("{}", String::from_utf8_lossy(&solution.stderr));
+        }
+
+        Ok(())
+    }
+
+    fn run_plugin(&mut self, changed: &[PathBuf]) -> anyhow::Result<()> {
+        if self.plugin_input.is_none() || changed.contains(&self.input_path) {
+            match fs::read_to_string(&self.input_path) {
+                Ok(input) => self.plugin_input = Some(input),
+                Err(error) => {
+                    print_status("run", false, &format!("could not read input: {error}"));
+                    return Ok(());
+                }
+            }
+        }
+        let input = self.plugin_input.as_deref().unwrap_or_default();
+
+        let target_dir = std::env::var("CARGO_TARGET_DIR").unwrap_or_else(|_| "target".into());
+        let plugin_path = Path::new(&target_dir).join("release/examples/libplugin.so");
+
+        let result = Plugin::load(&plugin_path)
+            .and_then(|plugin| plugin.run(AoCDate(self.year, self.day), input));
+
+        match result {
+            Ok(result) => {
+                print_status("run", true, "");
+                print_result(AoCDate(self.year, self.day), &result, false);
+            }
+            Err(error) => print_status("run", false, &format!("{error:#}")),
+        }
+
+        Ok(())
+    }
+}
+
+pub fn watch(year: u16, day: u8, plugin: bool, threads: Option<u16>) -> anyhow::Result<()> {
+    // the examples the tests run on live in the source file, the run reads the real input
+    let source_path = PathBuf::from(format!("./src/year{year}/day{day:02}.rs"));
+    let input_path = PathBuf::from(format!("./input/year{year}/day{day:02}.txt"));
+
+    if !source_path.is_file() {
+        bail!("no source file for day at {}", source_path.display());
+    }
+
+    if plugin && threads.is_some() {
+        bail!("--threads can't size the plugin's rayon pool, it brings its own copy of rayon");
+    }
+
+    let mut inotify = Inotify::new()?;
+    inotify.watch_file(&source_path)?;
+    let input_watched = inotify.watch_file(&input_path)?;
+
+    let mut session = Session {
+        year,
+        day,
+        input_path,
+        input_watched,
+        plugin_input: None,
+        plugin,
+        threads,
+    };
+
+    let mut run_count = 1;
+    let mut changed = vec![PathBuf::from("(initial run)")];
+
+    loop {
+        session.run(run_count, &changed)?;
+
+        changed = inotify.wait_for_changes()?;
+        run_count += 1;
+    }
+}
@@ -1,5 +1,35 @@
+use serde::Serialize;
+
+pub const INFO: crate::DayInfo = crate::DayInfo {
+    title: "Trebuchet?!",
+    tags: &["parsing"],
+    notes: "",
+    unfinished_parts: &[],
+};
+
 pub struct Parsed<'a>(&'a [u8]);
 
+// show the input as text instead of a list of bytes
+impl std::fmt::Debug for Parsed<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Parsed")
+            .field(&String::from_utf8_lossy(self.0))
+            .finish()
+    }
+}
+
+impl Serialize for Parsed<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&String::from_utf8_lossy(self.0))
+    }
+}
+
+impl crate::Inspect for Parsed<'_> {
+    fn stats(&self) -> Vec<(&'static str, serde_json::Value)> {
+        vec![("lines", self.0.split(|c| *c == b'\n').count().into())]
+    }
+}
+
 pub fn parse(input: &str) -> Parsed<'_> {
     let bytes = input.as_bytes();
 
@@ -47,7 +77,7 @@ pub fn part2(input: &Parsed) -> u64 {
         .map(|line| {
             let mut at = 0;
             let left_digit = loop {
-                let mut from_left = &line[at..];
+                let from_left = &line[at..];
                 assert!(!from_left.is_empty());
 
                 if let Some(actual_digit) = from_left
@@ -69,7 +99,7 @@ pub fn part2(input: &Parsed) -> u64 {
 
             let mut at = 0;
             let right_digit = loop {
-                let mut from_right = &line[..line.len() - at];
+                let from_right = &line[..line.len() - at];
                 assert!(!from_right.is_empty());
 
                 if let Some(actual_digit) = from_right
@@ -97,6 +127,7 @@ pub fn part2(input: &Parsed) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Inspect as _;
 
     const SAMPLE_INPUT: &str = r"1abc2
 pqr3stu8vwx
@@ -122,4 +153,9 @@ zoneight234
         let result = super::part2(&parse(SAMPLE_INPUT_PART_2));
         assert_eq!(result, 281)
     }
+
+    #[test]
+    fn stats() {
+        assert_eq!(parse(SAMPLE_INPUT).stats(), vec![("lines", 4.into())]);
+    }
 }
@@ -1,7 +1,21 @@
-use std::collections::BTreeMap;
+use serde::Serialize;
 
+pub const INFO: crate::DayInfo = crate::DayInfo {
+    title: "Historian Hysteria",
+    tags: &["sorting"],
+    notes: "",
+    unfinished_parts: &[],
+};
+
+#[derive(Debug, Serialize)]
 pub struct Parsed(Vec<u64>, Vec<u64>);
 
+impl crate::Inspect for Parsed {
+    fn stats(&self) -> Vec<(&'static str, serde_json::Value)> {
+        vec![("pairs", self.0.len().into())]
+    }
+}
+
 pub fn parse(input: &str) -> Parsed {
     let (left, right) = input
         .lines()
@@ -17,36 +31,62 @@ pub fn parse(input: &str) -> Parsed {
     Parsed(left, right)
 }
 
-pub fn part1(input: &Parsed) -> u64 {
-    let (mut left, mut right) = (input.0.clone(), input.1.clone());
-    left.sort();
-    right.sort();
+/// both lists sorted. part 1 pairs them up, part 2 finds equal numbers next to each other
+pub struct Context(Vec<u64>, Vec<u64>);
 
-    left.into_iter()
-        .zip(right)
-        .map(|(left, right)| left.abs_diff(right))
-        .sum()
+impl Context {
+    fn new(input: &Parsed) -> Context {
+        let (mut left, mut right) = (input.0.clone(), input.1.clone());
+        left.sort();
+        right.sort();
+
+        Context(left, right)
+    }
 }
-pub fn part2(input: &Parsed) -> u64 {
-    let (left, right) = (&input.0, &input.1);
 
-    let mut occurence_count: BTreeMap<u64, u64> = BTreeMap::new();
+pub fn part1_with(input: &Parsed) -> (u64, Context) {
+    let context = Context::new(input);
 
-    for number in right {
-        let entry = occurence_count
-            .entry(*number)
-            .and_modify(|occurences| *occurences += 1)
-            .or_insert(1);
-    }
+    let distance = context
+        .0
+        .iter()
+        .zip(&context.1)
+        .map(|(left, right)| left.abs_diff(*right))
+        .sum();
+
+    (distance, context)
+}
+
+pub fn part2_with(_input: &Parsed, Context(left, right): &Context) -> u64 {
+    let mut right = right.iter().peekable();
+
+    left.chunk_by(|a, b| a == b)
+        .map(|numbers| {
+            let number = numbers[0];
+            while right.next_if(|other| **other < number).is_some() {}
+
+            let mut occurences = 0;
+            while right.next_if(|other| **other == number).is_some() {
+                occurences += 1;
+            }
 
-    left.iter()
-        .map(|number| number * occurence_count.get(number).unwrap_or(&0))
+            number * numbers.len() as u64 * occurences
+        })
         .sum()
 }
 
+pub fn part1(input: &Parsed) -> u64 {
+    part1_with(input).0
+}
+
+pub fn part2(input: &Parsed) -> u64 {
+    part2_with(input, &Context::new(input))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Inspect as _;
 
     const SAMPLE_INPUT: &str = r"3   4
 4   3
@@ -66,4 +106,18 @@ mod tests {
         let result = super::part2(&parse(SAMPLE_INPUT));
         assert_eq!(result, 31)
     }
+
+    #[test]
+    fn part2_with_context() {
+        let input = parse(SAMPLE_INPUT);
+        let (_, context) = part1_with(&input);
+
+        let result = super::part2_with(&input, &context);
+        assert_eq!(result, 31)
+    }
+
+    #[test]
+    fn stats() {
+        assert_eq!(parse(SAMPLE_INPUT).stats(), vec![("pairs", 6.into())]);
+    }
 }
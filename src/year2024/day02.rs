@@ -1,4 +1,13 @@
-#[derive(Clone)]
+use serde::Serialize;
+
+pub const INFO: crate::DayInfo = crate::DayInfo {
+    title: "Red-Nosed Reports",
+    tags: &["parsing"],
+    notes: "",
+    unfinished_parts: &[],
+};
+
+#[derive(Clone, Debug, Serialize)]
 struct Report(Vec<u32>);
 
 impl Report {
@@ -7,19 +16,26 @@ impl Report {
         self.0.windows(2).all(|window| {
             let (left, right) = (window[0], window[1]);
             let window_descends = left > right;
-            if (*descending.get_or_insert(window_descends) != window_descends) {
+            if *descending.get_or_insert(window_descends) != window_descends {
                 return false;
             }
 
             let difference = left.abs_diff(right);
 
-            (difference > 0 && difference < 4)
+            difference > 0 && difference < 4
         })
     }
 }
 
+#[derive(Debug, Serialize)]
 pub struct Parsed(Vec<Report>);
 
+impl crate::Inspect for Parsed {
+    fn stats(&self) -> Vec<(&'static str, serde_json::Value)> {
+        vec![("reports", self.0.len().into())]
+    }
+}
+
 pub fn parse(input: &str) -> Parsed {
     Parsed(
         input
@@ -48,7 +64,7 @@ pub fn part2(input: &Parsed) -> u64 {
                 let mut dampened_report = (*report).clone();
                 dampened_report.0.remove(i);
 
-                if (dampened_report.is_safe()) {
+                if dampened_report.is_safe() {
                     return true;
                 }
             }
@@ -61,6 +77,7 @@ pub fn part2(input: &Parsed) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Inspect as _;
 
     const SAMPLE_INPUT: &str = r"7 6 4 2 1
 1 2 7 8 9
@@ -80,4 +97,9 @@ mod tests {
         let result = super::part2(&parse(SAMPLE_INPUT));
         assert_eq!(result, 4)
     }
+
+    #[test]
+    fn stats() {
+        assert_eq!(parse(SAMPLE_INPUT).stats(), vec![("reports", 6.into())]);
+    }
 }
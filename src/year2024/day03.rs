@@ -1,7 +1,22 @@
 use regex::Regex;
+use serde::Serialize;
 
+pub const INFO: crate::DayInfo = crate::DayInfo {
+    title: "Mull It Over",
+    tags: &["parsing", "regex"],
+    notes: "parse passes the input through, the parts run the regexes",
+    unfinished_parts: &[],
+};
+
+#[derive(Debug, Serialize)]
 pub struct Parsed(String);
 
+impl crate::Inspect for Parsed {
+    fn stats(&self) -> Vec<(&'static str, serde_json::Value)> {
+        vec![("bytes", self.0.len().into())]
+    }
+}
+
 pub fn parse(input: &str) -> Parsed {
     Parsed(input.to_owned()) // dont know what i need to parse yet, just pass the string
 }
@@ -65,6 +80,7 @@ pub fn part2(input: &Parsed) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Inspect as _;
 
     const SAMPLE_INPUT: &str =
         r"xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
@@ -83,4 +99,9 @@ mod tests {
         let result = super::part2(&parse(SAMPLE_INPUT_2));
         assert_eq!(result, 48)
     }
+
+    #[test]
+    fn stats() {
+        assert_eq!(parse(SAMPLE_INPUT).stats(), vec![("bytes", 71.into())]);
+    }
 }
@@ -1,5 +1,22 @@
+use serde::Serialize;
+
+pub const INFO: crate::DayInfo = crate::DayInfo {
+    title: "Ceres Search",
+    tags: &["grid"],
+    notes: "",
+    unfinished_parts: &[],
+};
+
+/// grid without newlines, width and height
+#[derive(Debug, Serialize)]
 pub struct Parsed(String, usize, usize);
 
+impl crate::Inspect for Parsed {
+    fn stats(&self) -> Vec<(&'static str, serde_json::Value)> {
+        vec![("width", self.1.into()), ("height", self.2.into())]
+    }
+}
+
 pub fn parse(input: &str) -> Parsed {
     let height = input.lines().count();
     let width = input
@@ -121,6 +138,7 @@ pub fn part2(input: &Parsed) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Inspect as _;
 
     const SAMPLE_INPUT: &str = r"MMMSXXMASM
 MSAMXMSMSA
@@ -144,4 +162,12 @@ MXMXAXMASX";
         let result = super::part2(&parse(SAMPLE_INPUT));
         assert_eq!(result, 9)
     }
+
+    #[test]
+    fn stats() {
+        assert_eq!(
+            parse(SAMPLE_INPUT).stats(),
+            vec![("width", 10.into()), ("height", 10.into())]
+        );
+    }
 }
@@ -1,14 +1,32 @@
-#[derive(Debug)]
+use serde::Serialize;
+
+pub const INFO: crate::DayInfo = crate::DayInfo {
+    title: "Print Queue",
+    tags: &["sorting"],
+    notes: "part 1 has an `is_valid_list` variant",
+    unfinished_parts: &[],
+};
+
+#[derive(Debug, Serialize)]
 pub struct PageOrderRule(u64, u64);
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct PageUpdate(Vec<u64>);
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct Parsed {
     page_orders: Vec<PageOrderRule>,
     page_updates: Vec<PageUpdate>,
 }
 
+impl crate::Inspect for Parsed {
+    fn stats(&self) -> Vec<(&'static str, serde_json::Value)> {
+        vec![
+            ("rules", self.page_orders.len().into()),
+            ("updates", self.page_updates.len().into()),
+        ]
+    }
+}
+
 pub fn parse(input: &str) -> Parsed {
     let (page_orders, page_updates) = input.split_once("\n\n").expect("invalid input format");
 
@@ -54,7 +72,6 @@ impl PageOrderRule {
             .unwrap_or(true)
     }
 
-    // TODO: refactor part 1 to use is_valid_list
     fn is_valid(&self, previous_numbers: &[u64], next: u64) -> bool {
         let (first, second) = (self.0, self.1);
 
@@ -81,6 +98,10 @@ impl PageUpdate {
         })
     }
 
+    fn valid_order_list(&self, rules: &[PageOrderRule]) -> bool {
+        rules.iter().all(|rule| rule.is_valid_list(&self.0))
+    }
+
     fn to_fixed(&self, rules: &[PageOrderRule]) -> Self {
         let mut fixed: Vec<u64> = Vec::with_capacity(self.0.len());
 
@@ -121,6 +142,21 @@ pub fn part1(input: &Parsed) -> u64 {
         .sum()
 }
 
+pub fn part1_valid_list(input: &Parsed) -> u64 {
+    input
+        .page_updates
+        .iter()
+        .filter(|&update| update.valid_order_list(&input.page_orders))
+        .map(|update| update.get_middle())
+        .sum()
+}
+
+pub const VARIANTS: &[crate::Variant<Parsed>] = &[crate::Variant {
+    part: 1,
+    label: "is_valid_list",
+    run: part1_valid_list,
+}];
+
 pub fn part2(input: &Parsed) -> u64 {
     input
         .page_updates
@@ -133,6 +169,7 @@ pub fn part2(input: &Parsed) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Inspect as _;
 
     const SAMPLE_INPUT: &str = r"47|53
 97|13
@@ -169,9 +206,23 @@ mod tests {
         assert_eq!(result, 143)
     }
 
+    #[test]
+    fn part1_valid_list() {
+        let result = super::part1_valid_list(&parse(SAMPLE_INPUT));
+        assert_eq!(result, 143)
+    }
+
     #[test]
     fn part2() {
         let result = super::part2(&parse(SAMPLE_INPUT));
         assert_eq!(result, 123)
     }
+
+    #[test]
+    fn stats() {
+        assert_eq!(
+            parse(SAMPLE_INPUT).stats(),
+            vec![("rules", 21.into()), ("updates", 6.into())]
+        );
+    }
 }
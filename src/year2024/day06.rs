@@ -1,7 +1,15 @@
 use rayon::prelude::*;
+use serde::Serialize;
 use std::collections::HashSet;
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub const INFO: crate::DayInfo = crate::DayInfo {
+    title: "Guard Gallivant",
+    tags: &["grid", "simulation", "parallel"],
+    notes: "",
+    unfinished_parts: &[],
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
 enum Heading {
     North,
     East,
@@ -9,14 +17,14 @@ enum Heading {
     West,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 enum Tile {
     Empty,
     Visited,
     Obstructed,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct Parsed {
     tiles: Vec<Tile>,
     character_position: usize,
@@ -25,6 +33,30 @@ pub struct Parsed {
     height: usize,
 }
 
+impl crate::Inspect for Parsed {
+    fn stats(&self) -> Vec<(&'static str, serde_json::Value)> {
+        let obstacles = self
+            .tiles
+            .iter()
+            .filter(|tile| **tile == Tile::Obstructed)
+            .count();
+
+        vec![
+            ("width", self.width.into()),
+            ("height", self.height.into()),
+            (
+                "guard",
+                serde_json::json!({
+                    "x": self.character_position % self.width,
+                    "y": self.character_position / self.width,
+                    "heading": format!("{:?}", self.heading),
+                }),
+            ),
+            ("obstacles", obstacles.into()),
+        ]
+    }
+}
+
 impl Parsed {
     fn get_next_position(&self) -> Option<usize> {
         let x = self.character_position % self.width;
@@ -51,7 +83,7 @@ impl Parsed {
                 continue;
             }
 
-            if (!visited.insert((next, self.heading.clone()))) {
+            if !visited.insert((next, self.heading.clone())) {
                 // cycle detected
                 return false;
             }
@@ -99,41 +131,75 @@ pub fn parse(input: &str) -> Parsed {
     }
 }
 
-pub fn part1(input: &Parsed) -> u64 {
-    let mut map = input.clone();
-    map.walk();
+/// the tiles the guard visits on the unchanged map, a new obstacle only matters on one of them
+pub struct Context {
+    visited: Vec<usize>,
+}
 
-    map.tiles
-        .iter()
-        .filter(|tile| **tile == Tile::Visited)
-        .count() as u64
+impl Context {
+    fn new(input: &Parsed) -> Context {
+        let mut map = input.clone();
+        map.walk();
+
+        Context {
+            visited: map
+                .tiles
+                .iter()
+                .enumerate()
+                .filter(|(_, tile)| **tile == Tile::Visited)
+                .map(|(i, _)| i)
+                .collect(),
+        }
+    }
 }
 
-pub fn part2(input: &Parsed) -> u64 {
-    let mut map = input.clone();
-    map.walk();
-
-    map.tiles
-        .into_par_iter()
-        .enumerate()
-        .filter(|(_, tile)| *tile == Tile::Visited)
-        .map(|(i, _)| i)
-        .filter(|new_obstacle_index| {
-            if *new_obstacle_index == input.character_position {
-                return false;
-            }
+pub fn part1_with(input: &Parsed) -> (u64, Context) {
+    let context = Context::new(input);
 
-            let mut map = input.clone();
-            map.tiles[*new_obstacle_index] = Tile::Obstructed;
+    (context.visited.len() as u64, context)
+}
 
-            !map.walk()
+/// obstacles tried per trace span. a span per obstacle costs more than the walk it measures,
+/// one per chunk still shows how the work spreads over the workers
+const OBSTACLES_PER_SPAN: usize = 64;
+
+pub fn part2_with(input: &Parsed, context: &Context) -> u64 {
+    context
+        .visited
+        .par_chunks(OBSTACLES_PER_SPAN)
+        .map(|new_obstacle_indices| {
+            let _span =
+                tracing::trace_span!("obstacles", count = new_obstacle_indices.len()).entered();
+
+            new_obstacle_indices
+                .iter()
+                .filter(|new_obstacle_index| {
+                    if **new_obstacle_index == input.character_position {
+                        return false;
+                    }
+
+                    let mut map = input.clone();
+                    map.tiles[**new_obstacle_index] = Tile::Obstructed;
+
+                    !map.walk()
+                })
+                .count() as u64
         })
-        .count() as u64
+        .sum()
+}
+
+pub fn part1(input: &Parsed) -> u64 {
+    part1_with(input).0
+}
+
+pub fn part2(input: &Parsed) -> u64 {
+    part2_with(input, &Context::new(input))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Inspect as _;
 
     const SAMPLE_INPUT: &str = r"....#.....
 .........#
@@ -157,4 +223,29 @@ mod tests {
         let result = super::part2(&parse(SAMPLE_INPUT));
         assert_eq!(result, 6)
     }
+
+    #[test]
+    fn part2_with_context() {
+        let input = parse(SAMPLE_INPUT);
+        let (_, context) = part1_with(&input);
+
+        let result = super::part2_with(&input, &context);
+        assert_eq!(result, 6)
+    }
+
+    #[test]
+    fn stats() {
+        assert_eq!(
+            parse(SAMPLE_INPUT).stats(),
+            vec![
+                ("width", 10.into()),
+                ("height", 10.into()),
+                (
+                    "guard",
+                    serde_json::json!({ "x": 4, "y": 6, "heading": "North" })
+                ),
+                ("obstacles", 8.into()),
+            ]
+        );
+    }
 }
@@ -1,8 +1,17 @@
 use rayon::prelude::*;
-use std::{fmt::format, iter::repeat_n};
+use std::iter::repeat_n;
 
 use itertools::Itertools as _;
+use serde::Serialize;
 
+pub const INFO: crate::DayInfo = crate::DayInfo {
+    title: "Bridge Repair",
+    tags: &["brute force", "parallel"],
+    notes: "",
+    unfinished_parts: &[],
+};
+
+#[derive(Debug, Serialize)]
 struct PartialEquation {
     result: u64,
     numbers: Vec<u64>,
@@ -35,8 +44,25 @@ impl PartialEquation {
     }
 }
 
+#[derive(Debug, Serialize)]
 pub struct Parsed(Vec<PartialEquation>);
 
+impl crate::Inspect for Parsed {
+    fn stats(&self) -> Vec<(&'static str, serde_json::Value)> {
+        let most_numbers = self
+            .0
+            .iter()
+            .map(|equation| equation.numbers.len())
+            .max()
+            .unwrap_or(0);
+
+        vec![
+            ("equations", self.0.len().into()),
+            ("most numbers", most_numbers.into()),
+        ]
+    }
+}
+
 pub fn parse(input: &str) -> Parsed {
     Parsed(
         input
@@ -70,24 +96,35 @@ pub fn part1(input: &Parsed) -> u64 {
         .sum()
 }
 
+/// equations checked per trace span, most equations take less time to check than a span to record
+const EQUATIONS_PER_SPAN: usize = 16;
+
 pub fn part2(input: &Parsed) -> u64 {
     input
         .0
-        .par_iter()
-        .filter(|equation| {
-            equation.has_valid_equation(&[
-                Operation::Add,
-                Operation::Multiply,
-                Operation::Concatenate,
-            ])
+        .par_chunks(EQUATIONS_PER_SPAN)
+        .map(|equations| {
+            let _span = tracing::trace_span!("equations", count = equations.len()).entered();
+
+            equations
+                .iter()
+                .filter(|equation| {
+                    equation.has_valid_equation(&[
+                        Operation::Add,
+                        Operation::Multiply,
+                        Operation::Concatenate,
+                    ])
+                })
+                .map(|equation| equation.result)
+                .sum::<u64>()
         })
-        .map(|equation| equation.result)
         .sum()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Inspect as _;
 
     const SAMPLE_INPUT: &str = r"190: 10 19
 3267: 81 40 27
@@ -110,4 +147,12 @@ mod tests {
         let result = super::part2(&parse(SAMPLE_INPUT));
         assert_eq!(result, 11387)
     }
+
+    #[test]
+    fn stats() {
+        assert_eq!(
+            parse(SAMPLE_INPUT).stats(),
+            vec![("equations", 9.into()), ("most numbers", 4.into())]
+        );
+    }
 }
@@ -1,17 +1,43 @@
 use std::collections::BTreeMap;
 
 use itertools::Itertools as _;
+use serde::Serialize;
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub const INFO: crate::DayInfo = crate::DayInfo {
+    title: "Resonant Collinearity",
+    tags: &["grid", "geometry"],
+    notes: "",
+    unfinished_parts: &[],
+};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
 pub struct Position(i64, i64);
 
+#[derive(Debug, Serialize)]
 pub struct Antennas(Vec<Position>);
+#[derive(Debug, Serialize)]
 pub struct Parsed {
     width: i64,
     height: i64,
     antennas: BTreeMap<char, Antennas>,
 }
 
+impl crate::Inspect for Parsed {
+    fn stats(&self) -> Vec<(&'static str, serde_json::Value)> {
+        let frequencies = self
+            .antennas
+            .iter()
+            .map(|(frequency, antennas)| (frequency.to_string(), antennas.0.len().into()))
+            .collect::<serde_json::Map<_, _>>();
+
+        vec![
+            ("width", self.width.into()),
+            ("height", self.height.into()),
+            ("frequencies", frequencies.into()),
+        ]
+    }
+}
+
 pub fn parse(input: &str) -> Parsed {
     let mut antennas: BTreeMap<char, Antennas> = BTreeMap::new();
 
@@ -94,6 +120,7 @@ pub fn part2(input: &Parsed) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Inspect as _;
 
     const SAMPLE_INPUT: &str = r"............
 ........0...
@@ -119,4 +146,16 @@ mod tests {
         let result = super::part2(&parse(SAMPLE_INPUT));
         assert_eq!(result, 34)
     }
+
+    #[test]
+    fn stats() {
+        assert_eq!(
+            parse(SAMPLE_INPUT).stats(),
+            vec![
+                ("width", 12.into()),
+                ("height", 12.into()),
+                ("frequencies", serde_json::json!({ "0": 4, "A": 3 })),
+            ]
+        );
+    }
 }
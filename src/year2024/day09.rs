@@ -1,5 +1,27 @@
+use serde::Serialize;
+
+pub const INFO: crate::DayInfo = crate::DayInfo {
+    title: "Disk Fragmenter",
+    tags: &["simulation"],
+    notes: "moving whole files for part 2 is not implemented yet",
+    unfinished_parts: &[2],
+};
+
+/// one entry per block, `None` is free space
+#[derive(Debug, Serialize)]
 pub struct Parsed(Vec<Option<u64>>);
 
+impl crate::Inspect for Parsed {
+    fn stats(&self) -> Vec<(&'static str, serde_json::Value)> {
+        let free = self.0.iter().filter(|block| block.is_none()).count();
+
+        vec![
+            ("blocks", self.0.len().into()),
+            ("free blocks", free.into()),
+        ]
+    }
+}
+
 pub fn parse(input: &str) -> Parsed {
     Parsed(
         input
@@ -44,6 +66,7 @@ impl Parsed {
         Parsed(defragmented)
     }
 
+    #[allow(dead_code)] // not wired up until it works
     fn to_defragmented_files(&self) -> Self {
         todo!("this doesnt work yet");
 
@@ -105,14 +128,15 @@ impl Parsed {
 pub fn part1(input: &Parsed) -> u64 {
     input.to_defragmented().checksum()
 }
-pub fn part2(input: &Parsed) -> u64 {
+pub fn part2(_input: &Parsed) -> u64 {
     0
-    // input.to_defragmented_files().checksum();
+    // _input.to_defragmented_files().checksum();
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Inspect as _;
 
     const SAMPLE_INPUT: &str = r"2333133121414131402";
 
@@ -128,4 +152,12 @@ mod tests {
         let result = super::part2(&parse(SAMPLE_INPUT));
         assert_eq!(result, 2858)
     }
+
+    #[test]
+    fn stats() {
+        assert_eq!(
+            parse(SAMPLE_INPUT).stats(),
+            vec![("blocks", 42.into()), ("free blocks", 14.into())]
+        );
+    }
 }
@@ -1,8 +1,11 @@
-#[derive(Debug)]
-enum Rotation {
-    Left(i32),
-    Right(i32),
-}
+use serde::Serialize;
+
+pub const INFO: crate::DayInfo = crate::DayInfo {
+    title: "Secret Entrance",
+    tags: &["simulation"],
+    notes: "part 2 has a `branchless` variant",
+    unfinished_parts: &[],
+};
 
 fn try_parse_rotation(value: &[u8]) -> Result<i32, ()> {
     let (direction, amount) = value.split_first_chunk::<1>().ok_or(())?;
@@ -15,16 +18,23 @@ fn try_parse_rotation(value: &[u8]) -> Result<i32, ()> {
     }
 }
 
+/// rotations, negative to the left
+#[derive(Debug, Serialize)]
 pub struct Parsed(Vec<i32>);
 
+impl crate::Inspect for Parsed {
+    fn stats(&self) -> Vec<(&'static str, serde_json::Value)> {
+        vec![("rotations", self.0.len().into())]
+    }
+}
+
 pub fn parse(input: &str) -> Parsed {
     Parsed(
         input
             .as_bytes()
             .split(|c| *c == b'\n')
-            .filter_map(|line| {
-                (!line.is_empty()).then(|| try_parse_rotation(line).expect("line not a rotation"))
-            })
+            .filter(|line| !line.is_empty())
+            .map(|line| try_parse_rotation(line).expect("line not a rotation"))
             .collect(),
     )
 }
@@ -57,12 +67,7 @@ pub fn part2(input: &Parsed) -> u64 {
             .abs() as u64;
 
             // NOTE: the compiler can optimize the following code to conditional moves.
-            // the explicit form looks like this:
-            //
-            // let negative = rotation.is_negative() as i32;
-            // let landed_on_zero = (dial % 100 == 0) as i32;
-            // let started_on_zero = (previous_dial % 100 == 0) as i32;
-            // return (clicks + negative * (landed_on_zero - started_on_zero)) as u64;
+            // the explicit form is `part2_branchless`
 
             // do click if we land on 0 from turning left
             if rotation.is_negative() && dial % 100 == 0 {
@@ -79,9 +84,38 @@ pub fn part2(input: &Parsed) -> u64 {
         .sum()
 }
 
+pub fn part2_branchless(input: &Parsed) -> u64 {
+    let mut dial = 50;
+
+    input
+        .0
+        .iter()
+        .map(|rotation| {
+            let previous_dial = dial;
+            dial += *rotation;
+
+            let clicks = ((previous_dial as f32 / 100.0).floor() - ((dial as f32 / 100.0).floor()))
+                .abs() as i32;
+
+            let negative = rotation.is_negative() as i32;
+            let landed_on_zero = (dial % 100 == 0) as i32;
+            let started_on_zero = (previous_dial % 100 == 0) as i32;
+
+            (clicks + negative * (landed_on_zero - started_on_zero)) as u64
+        })
+        .sum()
+}
+
+pub const VARIANTS: &[crate::Variant<Parsed>] = &[crate::Variant {
+    part: 2,
+    label: "branchless",
+    run: part2_branchless,
+}];
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Inspect as _;
 
     const SAMPLE_INPUT: &str = r"L68
 L30
@@ -106,6 +140,12 @@ L82";
         assert_eq!(result, 6)
     }
 
+    #[test]
+    fn part2_branchless() {
+        let result = super::part2_branchless(&parse(SAMPLE_INPUT));
+        assert_eq!(result, 6)
+    }
+
     #[test]
     fn counts_clicks_correctly() {
         // Clicked 1 times rotating Left(24) from 24 to 0
@@ -130,4 +170,9 @@ L122
         // Clicked 1 time rotating Left(100) from 50 to 50
         assert_eq!(super::part2(&parse(r"L50",)), 1);
     }
+
+    #[test]
+    fn stats() {
+        assert_eq!(parse(SAMPLE_INPUT).stats(), vec![("rotations", 10.into())]);
+    }
 }
@@ -1,4 +1,13 @@
-#[derive(Clone, Copy, Debug)]
+use serde::Serialize;
+
+pub const INFO: crate::DayInfo = crate::DayInfo {
+    title: "Gift Shop",
+    tags: &["math"],
+    notes: "",
+    unfinished_parts: &[],
+};
+
+#[derive(Clone, Copy, Debug, Serialize)]
 struct Range {
     start: u64,
     end: u64,
@@ -47,7 +56,7 @@ impl AoC2025Day02Ext for u64 {
                 continue;
             }
 
-            let pwr = 10_u64.pow(digits - 1 * d);
+            let pwr = 10_u64.pow(digits - d);
             let needle = self / pwr;
             let mut haystack = self - needle * pwr;
 
@@ -55,12 +64,12 @@ impl AoC2025Day02Ext for u64 {
                 let pwr = 10_u64.pow(check_n * d);
                 let compare = haystack / pwr;
 
-                if (compare != needle) {
+                if compare != needle {
                     // refuted this pattern, try next digit length
                     continue 'next_digit_length;
                 }
 
-                haystack = haystack - compare * pwr;
+                haystack -= compare * pwr;
             }
 
             // could not refute this pattern, must be repeating
@@ -71,8 +80,15 @@ impl AoC2025Day02Ext for u64 {
     }
 }
 
+#[derive(Debug, Serialize)]
 pub struct Parsed(Vec<Range>);
 
+impl crate::Inspect for Parsed {
+    fn stats(&self) -> Vec<(&'static str, serde_json::Value)> {
+        vec![("ranges", self.0.len().into())]
+    }
+}
+
 pub fn parse(input: &str) -> Parsed {
     Parsed(
         input
@@ -115,6 +131,7 @@ pub fn part2(input: &Parsed) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Inspect as _;
 
     const SAMPLE_INPUT: &str = r"11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
 
@@ -129,4 +146,9 @@ mod tests {
         let result = super::part2(&parse(SAMPLE_INPUT));
         assert_eq!(result, 4174379265)
     }
+
+    #[test]
+    fn stats() {
+        assert_eq!(parse(SAMPLE_INPUT).stats(), vec![("ranges", 11.into())]);
+    }
 }
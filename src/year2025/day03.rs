@@ -1,5 +1,18 @@
+use serde::Serialize;
+
+pub const INFO: crate::DayInfo = crate::DayInfo {
+    title: "Lobby",
+    tags: &["greedy"],
+    notes: "solves both parts in a single pass",
+    unfinished_parts: &[],
+};
+
+/// parsing already sums up the joltages for both parts
+#[derive(Debug, Serialize)]
 pub struct Parsed(u64, u64);
 
+impl crate::Inspect for Parsed {}
+
 trait AoC2025Day03Ext {
     fn get_joltage<const NUM_BATTERIES: usize>(&self) -> u64;
 }
@@ -27,15 +40,19 @@ impl AoC2025Day03Ext for &[u8] {
     }
 }
 
-pub fn parse(input: &str) -> Parsed {
-    let (part1, part2) = input
+/// both parts pick batteries from the same banks, so they are solved in one pass
+pub fn solve(input: &str) -> (u64, u64) {
+    input
         .as_bytes()
         .split(|c| *c == b'\n')
-        .filter_map(|line| {
-            (!line.is_empty()).then(|| (line.get_joltage::<2>(), line.get_joltage::<12>()))
-        })
+        .filter(|line| !line.is_empty())
+        .map(|line| (line.get_joltage::<2>(), line.get_joltage::<12>()))
         .reduce(|agg, next| (agg.0 + next.0, agg.1 + next.1))
-        .expect("input should not be empty");
+        .expect("input should not be empty")
+}
+
+pub fn parse(input: &str) -> Parsed {
+    let (part1, part2) = solve(input);
 
     Parsed(part1, part2)
 }
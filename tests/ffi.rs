@@ -0,0 +1,121 @@
+#[path = "../examples/ffi.rs"]
+#[allow(dead_code)]
+mod ffi;
+
+use std::{fs, path::Path};
+
+use ffi::{
+    AOC_ABI_VERSION, AocDate, AocPartResult, AocStatus, aoc_abi_version, aoc_list_solutions,
+    aoc_solve,
+};
+
+const HEADER: &str = "include/advent_of_code.h";
+
+fn solve(year: u16, day: u8, part: u8, input: &[u8]) -> (AocStatus, AocPartResult) {
+    let mut result = AocPartResult::default();
+    let status = unsafe { aoc_solve(year, day, part, input.as_ptr(), input.len(), &mut result) };
+
+    (status, result)
+}
+
+#[test]
+fn header_is_up_to_date() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    let config = cbindgen::Config {
+        language: cbindgen::Language::C,
+        cpp_compat: true,
+        include_guard: Some("ADVENT_OF_CODE_H".to_owned()),
+        autogen_warning: Some(
+            "/* generated by cbindgen from examples/ffi.rs, do not edit */".to_owned(),
+        ),
+        documentation_style: cbindgen::DocumentationStyle::C99,
+        usize_is_size_t: true,
+        enumeration: cbindgen::EnumConfig {
+            rename_variants: cbindgen::RenameRule::QualifiedScreamingSnakeCase,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let mut header = Vec::new();
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(root.join("examples/ffi.rs"))
+        .generate()
+        .expect("failed to generate header")
+        .write(&mut header);
+    let header = String::from_utf8(header).expect("header is utf-8");
+
+    let path = root.join(HEADER);
+    if std::env::var_os("UPDATE_FFI_HEADER").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, &header).unwrap();
+    }
+
+    assert!(
+        fs::read_to_string(&path).is_ok_and(|existing| existing == header),
+        "{HEADER} is out of date, regenerate it with `UPDATE_FFI_HEADER=1 cargo test --test ffi`"
+    );
+}
+
+#[test]
+fn layout_matches_abi_version() {
+    assert_eq!(aoc_abi_version(), AOC_ABI_VERSION);
+    // changing the layout breaks compiled harnesses, bump the version together with this
+    assert_eq!(
+        (
+            AOC_ABI_VERSION,
+            size_of::<AocPartResult>(),
+            size_of::<AocDate>()
+        ),
+        (2, 32, 4)
+    );
+}
+
+#[test]
+fn lists_solutions() {
+    let count = unsafe { aoc_list_solutions(std::ptr::null_mut(), 0) };
+    let mut dates = vec![AocDate { year: 0, day: 0 }; count];
+    let written = unsafe { aoc_list_solutions(dates.as_mut_ptr(), dates.len()) };
+
+    assert_eq!(written, count);
+    assert!(dates.contains(&AocDate { year: 2024, day: 1 }));
+    assert!(dates.is_sorted_by_key(|date| (date.year, date.day)));
+}
+
+#[test]
+fn solves_single_parts() {
+    let input = b"3   4\n4   3\n2   5\n1   3\n3   9\n3   3";
+
+    let (status, result) = solve(2024, 1, 1, input);
+    assert_eq!(status, AocStatus::Ok);
+    assert_eq!(result.answer, 11);
+
+    let (status, result) = solve(2024, 1, 2, input);
+    assert_eq!(status, AocStatus::Ok);
+    assert_eq!(result.answer, 31);
+}
+
+#[test]
+fn reports_errors_instead_of_panicking() {
+    assert_eq!(solve(2024, 9, 1, b"").0, AocStatus::UnknownDay);
+    assert_eq!(solve(2024, 1, 3, b"").0, AocStatus::InvalidPart);
+    assert_eq!(solve(2024, 1, 1, &[0xff]).0, AocStatus::InvalidInput);
+    assert_eq!(solve(2024, 5, 1, b"not an input").0, AocStatus::Panicked);
+
+    let mut result = AocPartResult::default();
+    let status = unsafe { aoc_solve(2024, 1, 1, std::ptr::null(), 0, std::ptr::null_mut()) };
+    assert_eq!(status, AocStatus::NullPointer);
+    let status = unsafe { aoc_solve(2024, 1, 1, std::ptr::null(), 1, &mut result) };
+    assert_eq!(status, AocStatus::NullPointer);
+}
+
+#[test]
+fn accepts_null_for_empty_input() {
+    let mut result = AocPartResult::default();
+    let status = unsafe { aoc_solve(2024, 1, 1, std::ptr::null(), 0, &mut result) };
+
+    assert_eq!(status, AocStatus::Ok);
+    assert_eq!(result.answer, 0);
+}
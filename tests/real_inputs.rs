@@ -0,0 +1,72 @@
+//! runs every registered solution against the real puzzle input and compares the results to
+//! the known answers in `answers/yearYYYY/dayDD.txt`. days without an input or an answer
+//! are reported as ignored.
+
+use std::{
+    path::Path,
+    sync::{Arc, OnceLock},
+};
+
+use advent_of_code::{AoCDate, get_solutions, load_all_input_files, parse_answers};
+use libtest_mimic::{Arguments, Failed, Trial};
+
+fn main() {
+    let args = Arguments::from_args();
+
+    let mut input_files =
+        load_all_input_files(Path::new("./input")).expect("failed to load input files");
+    let answer_files =
+        load_all_input_files(Path::new("./answers")).expect("failed to load answer files");
+
+    let mut solutions = get_solutions().into_iter().collect::<Vec<_>>();
+    solutions.sort_unstable_by_key(|(date, _)| *date);
+
+    let mut trials = Vec::new();
+
+    for (date, solution) in solutions {
+        let AoCDate(year, day) = date;
+        let run_fn = solution.run_fn;
+        let input = input_files.remove(&date);
+        let answers = answer_files
+            .get(&date)
+            .map(|file| parse_answers(file))
+            .unwrap_or_default();
+
+        // both parts come out of a single run, share it between the two trials
+        let result: Arc<OnceLock<(u64, u64)>> = Arc::new(OnceLock::new());
+
+        for (part, expected) in answers.into_iter().enumerate() {
+            let name = format!("year{year}_day{day:02}_part{}_real", part + 1);
+            let missing = input.is_none() || expected.is_none();
+
+            let input = input.clone();
+            let result = Arc::clone(&result);
+
+            let trial = Trial::test(name, move || {
+                let (input, expected) = match (input, expected) {
+                    (Some(input), Some(expected)) => (input, expected),
+                    (None, _) => return Err(Failed::from("no input file for day")),
+                    (_, None) => return Err(Failed::from("no known answer for part")),
+                };
+
+                let (part1, part2) = *result.get_or_init(|| {
+                    let result = run_fn(input);
+                    (result.part1, result.part2)
+                });
+
+                let actual = if part == 0 { part1 } else { part2 };
+
+                if actual == expected {
+                    Ok(())
+                } else {
+                    Err(Failed::from(format!("expected {expected}, got {actual}")))
+                }
+            })
+            .with_ignored_flag(missing);
+
+            trials.push(trial);
+        }
+    }
+
+    libtest_mimic::run(&args, trials).exit();
+}
@@ -0,0 +1,91 @@
+use std::{
+    io::{Read as _, Write as _},
+    net::{SocketAddr, TcpStream},
+    sync::OnceLock,
+};
+
+use advent_of_code::server::SolutionServer;
+use serde_json::Value;
+
+fn server() -> SocketAddr {
+    static ADDR: OnceLock<SocketAddr> = OnceLock::new();
+
+    *ADDR.get_or_init(|| {
+        let server = SolutionServer::bind("127.0.0.1:0").expect("failed to bind");
+        let addr = server.local_addr().expect("bound to an ip address");
+        std::thread::spawn(move || server.run());
+        addr
+    })
+}
+
+fn request(method: &str, path: &str, body: &str) -> (u16, Value) {
+    let mut stream = TcpStream::connect(server()).expect("failed to connect");
+    write!(
+        stream,
+        "{method} {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{body}",
+        body.len()
+    )
+    .expect("failed to send request");
+
+    let mut response = String::new();
+    stream
+        .read_to_string(&mut response)
+        .expect("failed to read response");
+
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .expect("response has a body");
+    let status = head
+        .split_whitespace()
+        .nth(1)
+        .and_then(|status| status.parse().ok())
+        .expect("response has a status");
+
+    (status, serde_json::from_str(body).expect("body is json"))
+}
+
+#[test]
+fn lists_solutions() {
+    let (status, body) = request("GET", "/solutions", "");
+
+    assert_eq!(status, 200);
+    assert!(
+        body.as_array()
+            .expect("list of solutions")
+            .contains(&serde_json::json!({ "year": 2024, "day": 1 }))
+    );
+}
+
+#[test]
+fn solves_posted_input() {
+    let (status, body) = request(
+        "POST",
+        "/year2024/day01",
+        "3   4\n4   3\n2   5\n1   3\n3   9\n3   3",
+    );
+
+    assert_eq!(status, 200);
+    assert_eq!(body["part1"]["answer"], 11);
+    assert_eq!(body["part2"]["answer"], 31);
+    assert!(body["parse"]["time_ns"].is_u64());
+}
+
+#[test]
+fn rejects_unknown_days_and_invalid_input() {
+    assert_eq!(request("POST", "/year2024/day09", "").0, 404);
+    assert_eq!(request("GET", "/year2024/day01", "").0, 405);
+    assert_eq!(request("POST", "/year2024/day05", "not an input").0, 422);
+}
+
+#[test]
+fn keeps_serving_after_a_bad_request() {
+    let mut stream = TcpStream::connect(server()).expect("failed to connect");
+    stream
+        .write_all(b"not http at all\r\n\r\n")
+        .expect("failed to send request");
+    // the client hangs up without waiting for the response
+    drop(stream);
+
+    let (status, _) = request("POST", "/year2024/day01", "3   4\n4   3");
+    assert_eq!(status, 200);
+}